@@ -0,0 +1,162 @@
+//! Measures a frame of an open [`PickList`] menu with many options: laying
+//! it out, hovering and scrolling it, and drawing it.
+//!
+//! The menu only lays out and draws the rows around its viewport, and the
+//! [`PickList`] filters and decorates its options once per `view`, so the
+//! time of a frame should barely grow with the number of options:
+//!
+//! ```text
+//! cargo bench --bench pick_list
+//! ```
+use iced::widget::text;
+use sweeten::core::keyboard::{self, key};
+use sweeten::core::widget::Tree;
+use sweeten::core::{
+    Event, Layout, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+    clipboard, layout, mouse, renderer,
+};
+use sweeten::widget::PickList;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The numbers of options to compare.
+const COUNTS: [usize; 2] = [1_000, 50_000];
+
+/// The number of frames measured for each case.
+const FRAMES: u32 = 200;
+
+/// The size of the window around the [`PickList`].
+const WINDOW: Size = Size::new(400.0, 600.0);
+
+type Menu<'a> = PickList<'a, String, &'a [String], &'a String, (), Theme, ()>;
+
+fn main() {
+    for count in COUNTS {
+        let options: Vec<String> =
+            (0..count).map(|i| format!("Option {i}")).collect();
+
+        let plain = measure(&options, |menu| menu, "");
+        let searching = measure(&options, |menu| menu.searchable(true), "9");
+        let decorated = measure(&options, decorate, "");
+
+        println!(
+            "{count:>6} options: {plain:>10.2?} plain, \
+             {searching:>10.2?} searching, {decorated:>10.2?} decorated"
+        );
+    }
+}
+
+/// Adds an icon to every option, disables some of them with a reason,
+/// and groups them by hundreds.
+fn decorate(menu: Menu<'_>) -> Menu<'_> {
+    menu.icon(|_| Some(text("*").into()))
+        .disabled(|options| (0..options.len()).map(|i| i % 7 == 0).collect())
+        .disabled_reason(|_| Some(String::from("Unavailable")))
+        .groups(|option| {
+            let hundreds = option.len().saturating_sub(2);
+
+            Some(&option[..hundreds])
+        })
+}
+
+/// Opens a [`PickList`] with the given options, configured by the given
+/// function, types the given query, and returns the average time of a frame
+/// afterwards.
+fn measure<'a>(
+    options: &'a [String],
+    configure: impl Fn(Menu<'a>) -> Menu<'a>,
+    query: &str,
+) -> Duration {
+    let mut widget =
+        configure(PickList::new(options, options.last(), |_| ()).width(200));
+
+    let mut messages = Vec::new();
+    let mut tree = Tree::new(&widget as &dyn Widget<_, _, _>);
+
+    let node =
+        widget.layout(&mut tree, &(), &layout::Limits::new(Size::ZERO, WINDOW));
+
+    // Open the menu, which scrolls to the selected option at the end
+    let cursor = mouse::Cursor::Available(Point::new(10.0, 10.0));
+
+    let mut events = vec![Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))];
+
+    events.extend(query.chars().map(|c| {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character(c.to_string().into()),
+            modified_key: keyboard::Key::Character(c.to_string().into()),
+            physical_key: key::Physical::Unidentified(
+                key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: Some(c.to_string().into()),
+            repeat: false,
+        })
+    }));
+
+    for event in &events {
+        widget.update(
+            &mut tree,
+            event,
+            Layout::new(&node),
+            cursor,
+            &(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+            &Rectangle::with_size(WINDOW),
+        );
+    }
+
+    let start = Instant::now();
+
+    for frame in 0..FRAMES {
+        let mut overlay = widget
+            .overlay(
+                &mut tree,
+                Layout::new(&node),
+                &(),
+                &Rectangle::with_size(WINDOW),
+                Vector::ZERO,
+            )
+            .expect("the menu is open");
+
+        let menu = overlay.as_overlay_mut().layout(&(), WINDOW);
+        let cursor = mouse::Cursor::Available(
+            menu.bounds().center() + Vector::new(0.0, frame as f32),
+        );
+
+        for event in [
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: cursor.position().unwrap(),
+            }),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            }),
+        ] {
+            overlay.as_overlay_mut().update(
+                &event,
+                Layout::new(&menu),
+                cursor,
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+            );
+        }
+
+        overlay.as_overlay().draw(
+            &mut (),
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&menu),
+            cursor,
+        );
+
+        black_box(menu);
+    }
+
+    start.elapsed() / FRAMES
+}
@@ -1,10 +1,13 @@
 use iced::Length::Fill;
-use iced::widget::{button, container, text};
-use iced::{Center, Element, Task};
+use iced::widget::scrollable::{AbsoluteOffset, Direction, Scrollbar};
+use iced::widget::{Id, button, container, operation, scrollable, text};
+use iced::{Center, Element, Length, Task};
 
 use sweeten::widget::drag::DragEvent;
 use sweeten::widget::{column, row};
 
+const ROW: Id = Id::new("row");
+
 pub fn main() -> iced::Result {
     iced::application(App::new, App::update, App::view)
         .title("sweeten • drag and drop")
@@ -28,6 +31,7 @@ enum Mode {
 #[derive(Debug, Clone)]
 enum Message {
     Reorder(DragEvent),
+    Scroll(f32),
     SwitchMode(Mode),
 }
 
@@ -48,11 +52,14 @@ impl App {
         )
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::SwitchMode(mode) => {
                 self.mode = mode;
             }
+            Message::Scroll(x) => {
+                return operation::scroll_by(ROW, AbsoluteOffset { x, y: 0.0 });
+            }
             Message::Reorder(event) => match event {
                 DragEvent::Picked { .. } => {
                     // Optionally handle pick event
@@ -70,34 +77,50 @@ impl App {
                 }
             },
         }
+
+        Task::none()
     }
 
     fn view(&self) -> Element<'_, Message> {
-        let items = self.elements.iter().copied().map(pickme);
         let drag: Element<'_, Message> = match self.mode {
-            Mode::Column => column(items)
-                .spacing(5)
-                .deadband_zone(0.0)
-                .on_drag(Message::Reorder)
-                .align_x(Center)
-                .into(),
-            Mode::Row => row(items)
-                .spacing(5)
-                .on_drag(Message::Reorder)
-                .style(|_| row::Style {
-                    scale: 1.5,
-                    moved_item_overlay: iced::Color::BLACK.scale_alpha(0.75),
-                    ghost_background: iced::color![170, 0, 0]
-                        .scale_alpha(0.25)
-                        .into(),
-                    ghost_border: iced::Border {
-                        color: iced::Color::TRANSPARENT,
-                        width: 0.0,
-                        radius: 5.0.into(),
-                    },
-                })
-                .align_y(Center)
-                .into(),
+            Mode::Column => column(
+                self.elements
+                    .iter()
+                    .map(|label| pickme(label, Length::Shrink)),
+            )
+            .spacing(5)
+            .drag_threshold(0.0)
+            .on_drag(Message::Reorder)
+            .align_x(Center)
+            .into(),
+            // Wide enough to scroll; dragging near an edge scrolls it
+            Mode::Row => scrollable(
+                row(self.elements.iter().map(|label| pickme(label, 120)))
+                    .spacing(5)
+                    .on_drag(Message::Reorder)
+                    .on_drag_scroll(Message::Scroll)
+                    .style(|_| row::Style {
+                        scale: 1.5,
+                        dragged_background: iced::Color::TRANSPARENT.into(),
+                        moved_item_overlay: iced::Color::BLACK
+                            .scale_alpha(0.75),
+                        ghost_background: iced::color![170, 0, 0]
+                            .scale_alpha(0.25)
+                            .into(),
+                        ghost_border: iced::Border {
+                            color: iced::Color::TRANSPARENT,
+                            width: 0.0,
+                            radius: 5.0.into(),
+                        },
+                        dimmed_item_overlay: iced::Color::TRANSPARENT,
+                        drop_indicator: iced::color![170, 0, 0],
+                        drop_indicator_width: 2.0,
+                    })
+                    .align_y(Center),
+            )
+            .direction(Direction::Horizontal(Scrollbar::default()))
+            .id(ROW)
+            .into(),
         };
 
         container(
@@ -135,8 +158,9 @@ impl App {
     }
 }
 
-fn pickme(label: &str) -> Element<'_, Message> {
+fn pickme(label: &str, width: impl Into<Length>) -> Element<'_, Message> {
     container(text(label))
+        .width(width)
         .style(container::rounded_box)
         .padding(5)
         .into()
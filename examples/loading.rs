@@ -0,0 +1,93 @@
+//! Demonstrates a button that swaps its content while a task is running.
+//!
+//! This example shows:
+//! - `loading(bool)` - put the button in the loading state
+//! - `loading_content(Element)` - content drawn in place of the label
+//!   while loading, without resizing the button
+//! - An async task that flips the button back once it finishes
+//!
+//! Run with: `cargo run --example loading`
+
+use std::thread;
+use std::time::Duration;
+
+use iced::futures::channel::oneshot;
+use iced::{
+    Element, Fill, Task,
+    widget::{column, container, text},
+};
+
+use sweeten::focusable_button;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Submit,
+    Submitted,
+}
+
+#[derive(Default)]
+struct App {
+    is_submitting: bool,
+    submissions: u32,
+}
+
+impl App {
+    fn view(&self) -> Element<'_, Message> {
+        let submit = focusable_button(text("Submit"))
+            .on_press(Message::Submit)
+            .loading(self.is_submitting)
+            .loading_content(text("Sending…"));
+
+        container(
+            column![
+                text(format!("Submissions: {}", self.submissions)),
+                submit,
+            ]
+            .spacing(12),
+        )
+        .center(Fill)
+        .into()
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Submit => {
+                self.is_submitting = true;
+
+                return Task::perform(submit(), |_| Message::Submitted);
+            }
+            Message::Submitted => {
+                self.is_submitting = false;
+                self.submissions += 1;
+            }
+        }
+        Task::none()
+    }
+}
+
+fn main() -> iced::Result {
+    iced::application(App::default, update, view)
+        .title("Sweeten - loading button")
+        .centered()
+        .run()
+}
+
+fn update(app: &mut App, message: Message) -> iced::Task<Message> {
+    app.update(message)
+}
+
+fn view(app: &App) -> iced::Element<'_, Message> {
+    app.view()
+}
+
+/// Pretends to send something over the network.
+async fn submit() {
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = thread::spawn(move || {
+        thread::sleep(Duration::from_secs(2));
+        let _ = sender.send(());
+    });
+
+    let _ = receiver.await;
+}
@@ -1,8 +1,10 @@
 //! Demonstrates the enhanced text_input widget with focus/blur messages.
 //!
 //! This example shows:
-//! - `on_focus(Fn(String) -> Message)` - receive the current value when focused
+//! - `on_focus(Message)` - emit a message when focus is gained
 //! - `on_blur(Message)` - emit a message when focus is lost
+//! - `on_submit(Message)` - submit the form with Enter from either field
+//! - `clearable(Message)` - a "×" that clears a field when clicked
 //! - Form validation with inline error display
 //! - Tab navigation between fields
 //!
@@ -219,6 +221,8 @@ impl App {
                 .on_input(move |text| Message::InputChanged(field, text))
                 .on_focus(Message::InputFocused(field))
                 .on_blur(Message::InputBlurred(field))
+                .on_submit(Message::SubmitForm)
+                .clearable(Message::InputChanged(field, String::new()))
                 .width(Fill)
                 .secure(field == Field::Password);
 
@@ -295,7 +299,6 @@ impl App {
                         Some(Message::FocusNext)
                     }
                 }
-                Key::Named(Named::Enter) => Some(Message::SubmitForm),
                 _ => None,
             },
             _ => None,
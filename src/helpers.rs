@@ -1,19 +1,33 @@
 //! Helper functions to create widgets.
 
 use crate::core;
+#[cfg(any(
+    feature = "button",
+    feature = "column",
+    feature = "mouse_area",
+    feature = "row"
+))]
 use crate::core::Element;
+#[cfg(feature = "pick_list")]
 use crate::overlay::menu;
+#[cfg(feature = "mouse_area")]
 use crate::widget::MouseArea;
+#[cfg(feature = "column")]
 use crate::widget::column::{self, Column};
+#[cfg(feature = "pick_list")]
 use crate::widget::pick_list::{self, PickList};
+#[cfg(feature = "row")]
 use crate::widget::row::{self, Row};
+#[cfg(feature = "text_input")]
 use crate::widget::text_input::{self, TextInput};
 
+#[cfg(feature = "pick_list")]
 use std::borrow::Borrow;
 
 /// Creates a [`Column`] with the given children.
 ///
 /// Columns distribute their children vertically.
+#[cfg(feature = "column")]
 #[macro_export]
 macro_rules! column {
     () => (
@@ -27,6 +41,7 @@ macro_rules! column {
 /// Creates a [`Row`] with the given children.
 ///
 /// Rows distribute their children horizontally.
+#[cfg(feature = "row")]
 #[macro_export]
 macro_rules! row {
     () => (
@@ -38,6 +53,7 @@ macro_rules! row {
 }
 
 /// Creates a new [`Row`] with the given children.
+#[cfg(feature = "row")]
 pub fn row<'a, Message, Theme, Renderer>(
     children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
 ) -> Row<'a, Message, Theme, Renderer>
@@ -49,6 +65,7 @@ where
 }
 
 /// Creates a new [`Column`] with the given children.
+#[cfg(feature = "column")]
 pub fn column<'a, Message, Theme, Renderer>(
     children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
 ) -> Column<'a, Message, Theme, Renderer>
@@ -59,6 +76,60 @@ where
     Column::with_children(children)
 }
 
+/// Creates a new [`Column`] whose children can be reordered by dragging them.
+///
+/// When an item is dropped, `on_reorder` is called with its current index
+/// and the index it was dropped at; typically, the application moves the
+/// item with [`Vec::remove`] and [`Vec::insert`]. Picks and cancellations
+/// are not reported.
+///
+/// Dragging starts once the cursor moves 5 logical pixels away from where
+/// the item was pressed, so clicks still reach the children. The items can
+/// also be reordered with the keyboard, as described in [`Column::on_drag`].
+///
+/// The returned [`Column`] can be customized further; e.g. use
+/// [`Column::on_drag`] to be notified of every [`DragEvent`].
+///
+/// [`DragEvent`]: crate::widget::drag::DragEvent
+#[cfg(feature = "column")]
+pub fn reorderable_column<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    on_reorder: impl Fn(usize, usize) -> Message + 'a,
+) -> Column<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+    Theme: column::Catalog,
+{
+    Column::with_children(children).on_drop(on_reorder)
+}
+
+/// Creates a new [`Row`] whose children can be reordered by dragging them.
+///
+/// When an item is dropped, `on_reorder` is called with its current index
+/// and the index it was dropped at; typically, the application moves the
+/// item with [`Vec::remove`] and [`Vec::insert`]. Picks and cancellations
+/// are not reported.
+///
+/// Dragging starts once the cursor moves 5 logical pixels away from where
+/// the item was pressed, so clicks still reach the children. The items can
+/// also be reordered with the keyboard, as described in [`Row::on_drag`].
+///
+/// The returned [`Row`] can be customized further; e.g. use
+/// [`Row::on_drag`] to be notified of every [`DragEvent`].
+///
+/// [`DragEvent`]: crate::widget::drag::DragEvent
+#[cfg(feature = "row")]
+pub fn reorderable_row<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    on_reorder: impl Fn(usize, usize) -> Message + 'a,
+) -> Row<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+    Theme: row::Catalog,
+{
+    Row::with_children(children).on_drop(on_reorder)
+}
+
 /// Creates a new [`TextInput`].
 ///
 /// This is a sweetened version of [`iced`'s `text_input`] with support for
@@ -67,6 +138,7 @@ where
 /// [`iced`'s `text_input`]: https://docs.iced.rs/iced/widget/text_input/index.html
 /// [`on_focus`]: TextInput::on_focus
 /// [`on_blur`]: TextInput::on_blur
+#[cfg(feature = "text_input")]
 pub fn focusable_text_input<'a, Message, Theme, Renderer>(
     placeholder: &str,
     value: &str,
@@ -86,6 +158,7 @@ where
 ///
 /// [`iced`'s `pick_list`]: https://docs.iced.rs/iced/widget/pick_list/index.html
 /// [`disabled`]: PickList::disabled
+#[cfg(feature = "pick_list")]
 pub fn pick_list<'a, T, L, V, Message, Theme, Renderer>(
     options: L,
     selected: Option<V>,
@@ -109,6 +182,7 @@ where
 ///
 /// [`iced`'s `MouseArea`]: https://docs.iced.rs/iced/widget/struct.MouseArea.html
 /// [`Point`]: crate::core::Point
+#[cfg(feature = "mouse_area")]
 pub fn mouse_area<'a, Message, Theme, Renderer>(
     widget: impl Into<Element<'a, Message, Theme, Renderer>>,
 ) -> MouseArea<'a, Message, Theme, Renderer>
@@ -124,6 +198,7 @@ where
 /// and [`on_blur`] messages, making it focusable.
 ///
 /// [`iced`'s `Button`]: https://docs.iced.rs/iced/widget/button/struct.Button.html
+#[cfg(feature = "button")]
 pub fn focusable_button<'a, Message, Theme, Renderer>(
     content: impl Into<Element<'a, Message, Theme, Renderer>>,
 ) -> crate::widget::button::Button<'a, Message, Theme, Renderer>
@@ -44,6 +44,15 @@
 //! - [`text_input`] — A text input field, with support for [`on_focus`] and
 //!   [`on_blur`] messages.
 //!
+//! Each widget lives behind a Cargo feature of the same name (`button`,
+//! `column`, `mouse_area`, `pick_list`, `row` and `text_input`), all of which
+//! are enabled by default. Disable the default features to compile only the
+//! widgets you use:
+//!
+//! ```toml
+//! sweeten = { version = "0.14", default-features = false, features = ["button"] }
+//! ```
+//!
 //! ## Usage
 //!
 //! Import the widgets you need from `sweeten::widget`:
@@ -71,15 +80,34 @@
 /// Importing this prelude lets you use [`button(...)`] / [`text_input(...)`]
 /// without colliding with the crate's `widget::*` modules.
 pub mod prelude {
+    #[cfg(feature = "button")]
     pub use crate::focusable_button as button;
+    #[cfg(feature = "text_input")]
     pub use crate::focusable_text_input as text_input;
 }
 
+#[cfg(any(
+    feature = "button",
+    feature = "column",
+    feature = "mouse_area",
+    feature = "pick_list",
+    feature = "row",
+    feature = "text_input"
+))]
 mod helpers;
 pub mod widget;
 
-pub use crate::helpers::focusable_button;
-pub use crate::helpers::focusable_text_input;
+#[cfg(any(test, feature = "test"))]
+pub mod test;
+
+#[cfg(any(
+    feature = "button",
+    feature = "column",
+    feature = "mouse_area",
+    feature = "pick_list",
+    feature = "row",
+    feature = "text_input"
+))]
 pub use helpers::*;
 
 // Re-exports to mirror iced_widget structure (allows minimal diff for widgets)
@@ -91,4 +119,5 @@ pub use iced_widget::{scrollable, text_editor};
 
 // Re-export widget modules at crate level (mirrors iced_widget's structure)
 pub use widget::overlay;
+#[cfg(feature = "text_input")]
 pub use widget::text_input;
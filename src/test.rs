@@ -0,0 +1,456 @@
+//! Drive widgets headlessly, without a window or a renderer.
+//!
+//! A [`Harness`] owns a single [`Element`], keeps its widget [`Tree`] and
+//! layout up to date, and dispatches [`Event`]s to it the same way a running
+//! application would. Every message published by the widget is collected, so
+//! a test can assert on exactly what the widget produced:
+//!
+//! ```no_run
+//! use iced::widget::text;
+//! use sweeten::core::{Point, Size};
+//! use sweeten::test::Harness;
+//! use sweeten::widget::Button;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Pressed,
+//! }
+//!
+//! let button: Button<'_, Message, iced::Theme, ()> =
+//!     Button::new(text("Press me"))
+//!         .width(100)
+//!         .height(30)
+//!         .on_press(Message::Pressed);
+//!
+//! let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+//!
+//! harness.click(Point::new(50.0, 15.0));
+//!
+//! assert_eq!(harness.messages(), vec![Message::Pressed]);
+//! ```
+//!
+//! The [`Renderer`] defaults to `()`, which lays out text without drawing
+//! anything. Overlays, such as the menu of a [`PickList`], are not part of
+//! the harness; only the root widget and its descendants receive events.
+//!
+//! [`Renderer`]: crate::core::Renderer
+//! [`PickList`]: crate::widget::PickList
+use crate::core::clipboard;
+use crate::core::keyboard::{self, Key, key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::widget::operation::Outcome;
+use crate::core::widget::{Operation, Tree};
+use crate::core::window;
+use crate::core::{Element, Event, Point, Rectangle, Shell, Size};
+
+use iced_runtime::futures::futures::StreamExt;
+use iced_runtime::{Action, Task, task};
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll, Waker};
+use std::time::Instant;
+
+/// A headless host for a single [`Element`].
+///
+/// See the [module documentation](self) for an example.
+#[allow(missing_debug_implementations)]
+pub struct Harness<'a, Message, Theme = crate::Theme, Renderer = ()> {
+    element: Element<'a, Message, Theme, Renderer>,
+    tree: Tree,
+    node: layout::Node,
+    size: Size,
+    renderer: Renderer,
+    cursor: mouse::Cursor,
+    modifiers: keyboard::Modifiers,
+    clipboard: Clipboard,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Harness<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer + Default,
+{
+    /// Builds the given element into a new [`Harness`] and lays it out
+    /// within the given [`Size`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+        size: Size,
+    ) -> Self {
+        Self::with_renderer(element, size, Renderer::default())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Harness<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Builds the given element into a new [`Harness`] that uses the given
+    /// `Renderer` for layout.
+    pub fn with_renderer(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+        size: Size,
+        renderer: Renderer,
+    ) -> Self {
+        let mut element = element.into();
+        let mut tree = Tree::new(element.as_widget());
+
+        let node = element.as_widget_mut().layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, size),
+        );
+
+        Self {
+            element,
+            tree,
+            node,
+            size,
+            renderer,
+            cursor: mouse::Cursor::Unavailable,
+            modifiers: keyboard::Modifiers::default(),
+            clipboard: Clipboard::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns the current [`Layout`] of the element.
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.node)
+    }
+
+    /// Returns the widget [`Tree`] of the element.
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// Returns the [`mouse::Interaction`] of the element at the current
+    /// position of the cursor.
+    pub fn mouse_interaction(&self) -> mouse::Interaction {
+        self.element.as_widget().mouse_interaction(
+            &self.tree,
+            Layout::new(&self.node),
+            self.cursor,
+            &Rectangle::with_size(self.size),
+            &self.renderer,
+        )
+    }
+
+    /// Returns the contents of the standard clipboard; e.g. after a copy.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.standard.as_deref()
+    }
+
+    /// Replaces the contents of the standard clipboard; e.g. before a paste.
+    pub fn set_clipboard(&mut self, contents: impl Into<String>) {
+        self.clipboard.standard = Some(contents.into());
+    }
+
+    /// Lays the element out again within the given [`Size`].
+    pub fn resize(&mut self, size: Size) {
+        self.size = size;
+        self.relayout();
+    }
+
+    /// Sets the [`keyboard::Modifiers`] held during the next events and
+    /// dispatches a [`keyboard::Event::ModifiersChanged`].
+    pub fn set_modifiers(&mut self, modifiers: keyboard::Modifiers) -> bool {
+        self.modifiers = modifiers;
+
+        self.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            modifiers,
+        )))
+    }
+
+    /// Dispatches an [`Event`] to the element and returns whether it was
+    /// captured.
+    ///
+    /// Cursor events also update the position of the cursor used for the
+    /// following events.
+    pub fn event(&mut self, event: Event) -> bool {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                self.cursor = mouse::Cursor::Available(position);
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.cursor = mouse::Cursor::Unavailable;
+            }
+            _ => {}
+        }
+
+        let mut shell = Shell::new(&mut self.messages);
+
+        self.element.as_widget_mut().update(
+            &mut self.tree,
+            &event,
+            Layout::new(&self.node),
+            self.cursor,
+            &self.renderer,
+            &mut self.clipboard,
+            &mut shell,
+            &Rectangle::with_size(self.size),
+        );
+
+        let is_captured = shell.is_event_captured();
+        let are_widgets_invalid = shell.are_widgets_invalid();
+        let is_layout_invalid = shell.is_layout_invalid();
+
+        if are_widgets_invalid {
+            self.tree.diff(&self.element);
+        }
+
+        if are_widgets_invalid || is_layout_invalid {
+            self.relayout();
+        }
+
+        is_captured
+    }
+
+    /// Dispatches a sequence of [`Event`]s to the element, in order.
+    pub fn events(&mut self, events: impl IntoIterator<Item = Event>) {
+        for event in events {
+            let _ = self.event(event);
+        }
+    }
+
+    /// Moves the cursor to the given position and returns whether the
+    /// event was captured.
+    pub fn move_cursor(&mut self, position: Point) -> bool {
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Moves the cursor to the given position and clicks the left button.
+    pub fn click(&mut self, position: Point) {
+        self.events([
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+    }
+
+    /// Presses and releases the given [`Key`], with the current modifiers.
+    pub fn tap_key(&mut self, key: impl Into<Key>) {
+        let key = key.into();
+        let text = match &key {
+            Key::Character(c) => Some(c.clone()),
+            _ => None,
+        };
+
+        self.events([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                modified_key: key.clone(),
+                physical_key: key::Physical::Unidentified(
+                    key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+                text,
+                repeat: false,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: key.clone(),
+                modified_key: key,
+                physical_key: key::Physical::Unidentified(
+                    key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+            }),
+        ]);
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.tap_key(Key::Character(c.to_string().into()));
+        }
+    }
+
+    /// Dispatches a [`window::Event::RedrawRequested`] for the given
+    /// [`Instant`], which drives animations and delayed messages.
+    pub fn redraw(&mut self, now: Instant) {
+        let _ = self.event(Event::Window(window::Event::RedrawRequested(now)));
+    }
+
+    /// Runs the given [`Operation`] on the element; e.g. one of the focus
+    /// operations in [`widget::operation`](crate::core::widget::operation).
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        self.element.as_widget_mut().operate(
+            &mut self.tree,
+            Layout::new(&self.node),
+            &self.renderer,
+            operation,
+        );
+    }
+
+    /// Runs the widget operations of the given [`Task`] on the element, like
+    /// the runtime would, and returns the values it produced.
+    ///
+    /// This is how the operations in [`widget::operation`] can be tested;
+    /// e.g. [`focus_next_where`]. Any other action of the [`Task`] is
+    /// ignored, and the [`Task`] is only polled until it would wait.
+    ///
+    /// [`widget::operation`]: crate::widget::operation
+    /// [`focus_next_where`]: crate::widget::operation::focus_next_where
+    pub fn run<T>(&mut self, task: Task<T>) -> Vec<T> {
+        let mut outputs = Vec::new();
+
+        let Some(mut stream) = task::into_stream(task) else {
+            return outputs;
+        };
+
+        let wake = Arc::new(Wake::default());
+        let waker = Waker::from(wake.clone());
+        let mut context = Context::from_waker(&waker);
+
+        loop {
+            let action = match stream.poll_next_unpin(&mut context) {
+                Poll::Ready(Some(action)) => action,
+                // Tasks yield once before producing values; only stop when
+                // the task is actually waiting for something
+                Poll::Pending if wake.0.swap(false, Ordering::Relaxed) => {
+                    continue;
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            };
+
+            match action {
+                Action::Output(output) => outputs.push(output),
+                Action::Widget(mut operation) => loop {
+                    self.operate(operation.as_mut());
+
+                    match operation.finish() {
+                        Outcome::Chain(next) => operation = next,
+                        _ => break,
+                    }
+                },
+                _ => {}
+            }
+        }
+
+        outputs
+    }
+
+    /// Takes the messages published by the element so far.
+    pub fn messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    fn relayout(&mut self) {
+        self.node = self.element.as_widget_mut().layout(
+            &mut self.tree,
+            &self.renderer,
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+    }
+}
+
+/// An in-memory clipboard, shared by the events of a [`Harness`].
+#[derive(Default)]
+struct Clipboard {
+    standard: Option<String>,
+    primary: Option<String>,
+}
+
+impl clipboard::Clipboard for Clipboard {
+    fn read(&self, kind: clipboard::Kind) -> Option<String> {
+        match kind {
+            clipboard::Kind::Standard => self.standard.clone(),
+            clipboard::Kind::Primary => self.primary.clone(),
+        }
+    }
+
+    fn write(&mut self, kind: clipboard::Kind, contents: String) {
+        match kind {
+            clipboard::Kind::Standard => self.standard = Some(contents),
+            clipboard::Kind::Primary => self.primary = Some(contents),
+        }
+    }
+}
+
+/// A [`Waker`] that remembers whether it was woken.
+#[derive(Default)]
+struct Wake(AtomicBool);
+
+impl std::task::Wake for Wake {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(all(test, feature = "button"))]
+mod tests {
+    use super::*;
+    use crate::core::Length;
+    use crate::widget::Button;
+    use crate::widget::operation;
+
+    use iced_widget::text;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Pressed,
+        Focused,
+    }
+
+    fn button<'a>() -> Button<'a, Message, crate::Theme, ()> {
+        Button::new(text("Press me"))
+            .width(100)
+            .height(30)
+            .on_press(Message::Pressed)
+            .on_focus(Message::Focused)
+    }
+
+    #[test]
+    fn builds_and_lays_out_the_element() {
+        let harness = Harness::new(button(), Size::new(200.0, 200.0));
+
+        assert_eq!(harness.layout().bounds().size(), Size::new(100.0, 30.0));
+    }
+
+    #[test]
+    fn collects_published_messages() {
+        let mut harness = Harness::new(button(), Size::new(200.0, 200.0));
+
+        harness.click(Point::new(50.0, 15.0));
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::Focused, Message::Pressed]
+        );
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn ignores_clicks_outside_of_the_layout() {
+        let mut harness = Harness::new(button(), Size::new(200.0, 200.0));
+
+        harness.click(Point::new(150.0, 150.0));
+
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn relays_out_on_resize() {
+        let button = button().width(Length::Fill);
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        harness.resize(Size::new(80.0, 200.0));
+
+        assert_eq!(harness.layout().bounds().width, 80.0);
+    }
+
+    #[test]
+    fn runs_widget_operations_of_tasks() {
+        let mut harness = Harness::new(button(), Size::new(200.0, 200.0));
+
+        assert_eq!(harness.run(operation::focused()), vec![None]);
+
+        let _ = harness.run::<()>(operation::focus_next_by_index());
+        harness.move_cursor(Point::new(50.0, 15.0));
+
+        assert_eq!(harness.messages(), vec![Message::Focused]);
+    }
+}
@@ -6,24 +6,43 @@
 //!
 //! [`iced`]: https://github.com/iced-rs/iced
 
+#[cfg(feature = "button")]
 pub mod button;
+#[cfg(feature = "column")]
 pub mod column;
+#[cfg(any(feature = "column", feature = "row"))]
 pub mod drag;
+#[cfg(feature = "mouse_area")]
 pub mod mouse_area;
+pub mod operation;
 pub mod overlay;
+#[cfg(feature = "pick_list")]
 pub mod pick_list;
+#[cfg(feature = "row")]
 pub mod row;
+#[cfg(feature = "text_input")]
 pub mod text_input;
 
+#[cfg(feature = "column")]
 pub use column::Column;
 
+#[cfg(feature = "button")]
 pub use button::Button;
+#[cfg(feature = "mouse_area")]
 pub use mouse_area::MouseArea;
+#[cfg(feature = "pick_list")]
 pub use pick_list::PickList;
+#[cfg(feature = "row")]
 pub use row::Row;
+#[cfg(feature = "text_input")]
 pub use text_input::TextInput;
 
 // Re-export helper functions and macros (same pattern as iced_widget)
+#[cfg(feature = "column")]
+pub use crate::column;
+#[cfg(feature = "button")]
 pub use crate::helpers::focusable_button;
+#[cfg(feature = "text_input")]
 pub use crate::helpers::focusable_text_input;
-pub use crate::{column, row};
+#[cfg(feature = "row")]
+pub use crate::row;
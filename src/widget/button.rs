@@ -29,15 +29,28 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::theme::palette;
+use crate::core::text;
+use crate::core::theme::{self, palette};
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget::Id;
 use crate::core::widget::operation::{Focusable, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
-    Background, Color, Element, Length, Padding, Rectangle, Size, Theme, Vector,
+    Alignment, Animation, Background, Color, Element, Length, Padding, Point,
+    Rectangle, Size, Theme, Transformation, Vector,
 };
 use crate::core::{Clipboard, Shell, Widget};
+use crate::widget::operation::{
+    EnabledState, PendingPress, TabIndex, WidgetState,
+};
+use iced_widget::Space;
+use iced_widget::container;
+use iced_widget::text as text_widget;
+use iced_widget::tooltip;
+use std::sync::atomic::{AtomicU64, Ordering};
+
 pub use iced_widget::button::Style;
 
 /// A clickable button.
@@ -79,27 +92,58 @@ pub struct Button<
     Theme: Catalog,
 {
     content: Element<'a, Message, Theme, Renderer>,
+    loading_content: Option<Element<'a, Message, Theme, Renderer>>,
     on_press: Option<OnPress<'a, Message>>,
+    on_hold: Option<(Duration, Message)>,
+    on_long_press: Option<Message>,
+    long_press_duration: Duration,
+    repeat: Option<(Duration, Duration)>,
+    on_double_press: Option<Message>,
+    on_right_press: Option<OnPress<'a, Message>>,
+    on_hover: Option<Message>,
+    on_unhover: Option<Message>,
+    double_press_interval: Duration,
     on_focus: Option<Message>,
     on_blur: Option<Message>,
     id: Option<Id>,
+    tab_index: i32,
     width: Length,
     height: Length,
     padding: Padding,
     clip: bool,
+    radius: Option<border::Radius>,
+    scale_animation: Option<ScaleSpec>,
+    is_disabled: bool,
+    disabled_tooltip: Option<Element<'a, Message, Theme, Renderer>>,
+    tooltip: Option<Element<'a, Message, Theme, Renderer>>,
+    tooltip_position: tooltip::Position,
+    wrap_in_tooltip: Option<WrapInTooltip<'a, Message, Theme, Renderer>>,
+    is_loading: bool,
+    spinner_slot: bool,
+    debounce: Option<Duration>,
+    debounce_mode: DebounceMode,
+    expanded: Option<bool>,
+    underline: bool,
+    autofocus: bool,
+    focus_on_click: bool,
+    blur_with_window: bool,
+    activation_keys: Option<Vec<keyboard::Key>>,
     class: Theme::Class<'a>,
+    last_status: Option<Status>,
 }
 
 enum OnPress<'a, Message> {
     Direct(Message),
     Closure(Box<dyn Fn() -> Message + 'a>),
+    WithModifiers(Box<dyn Fn(keyboard::Modifiers) -> Message + 'a>),
 }
 
 impl<'a, Message: Clone> OnPress<'a, Message> {
-    fn get(&self) -> Message {
+    fn get(&self, modifiers: keyboard::Modifiers) -> Message {
         match self {
             OnPress::Direct(message) => message.clone(),
             OnPress::Closure(f) => f(),
+            OnPress::WithModifiers(f) => f(modifiers),
         }
     }
 }
@@ -118,15 +162,44 @@ where
 
         Button {
             content,
+            loading_content: None,
             on_press: None,
+            on_hold: None,
+            on_long_press: None,
+            long_press_duration: LONG_PRESS_DURATION,
+            repeat: None,
+            on_double_press: None,
+            on_right_press: None,
+            on_hover: None,
+            on_unhover: None,
+            double_press_interval: DOUBLE_PRESS_INTERVAL,
             on_focus: None,
             on_blur: None,
             id: Some(Id::unique()),
+            tab_index: 0,
             width: size.width.fluid(),
             height: size.height.fluid(),
             padding: DEFAULT_PADDING,
             clip: false,
+            radius: None,
+            scale_animation: None,
+            is_disabled: false,
+            disabled_tooltip: None,
+            tooltip: None,
+            tooltip_position: tooltip::Position::Bottom,
+            wrap_in_tooltip: None,
+            is_loading: false,
+            spinner_slot: false,
+            debounce: None,
+            debounce_mode: DebounceMode::default(),
+            expanded: None,
+            underline: false,
+            autofocus: false,
+            focus_on_click: true,
+            blur_with_window: true,
+            activation_keys: None,
             class: Theme::default(),
+            last_status: None,
         }
     }
 
@@ -142,6 +215,19 @@ where
         self
     }
 
+    /// Sets the position of the [`Button`] in the order of
+    /// [`focus_next_by_index`], which is 0 by default.
+    ///
+    /// Widgets are focused in ascending order, and in tree order for equal
+    /// indices. With a negative index, the [`Button`] is skipped, but it can
+    /// still be focused by clicking it or with an operation.
+    ///
+    /// [`focus_next_by_index`]: crate::widget::operation::focus_next_by_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the height of the [`Button`].
     pub fn height(mut self, height: impl Into<Length>) -> Self {
         self.height = height.into();
@@ -156,7 +242,11 @@ where
 
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
-    /// Unless `on_press` is called, the [`Button`] will be disabled.
+    /// Unless `on_press`, [`on_hold`](Button::on_hold),
+    /// [`on_long_press`](Button::on_long_press) or
+    /// [`on_double_press`](Button::on_double_press) is called, the [`Button`]
+    /// will be disabled. A disabled [`Button`] cannot be focused,
+    /// and focus traversal skips it.
     pub fn on_press(mut self, on_press: Message) -> Self {
         self.on_press = Some(OnPress::Direct(on_press));
         self
@@ -178,6 +268,25 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Button`] is pressed,
+    /// from the [`keyboard::Modifiers`] held at the time.
+    ///
+    /// This lets a press behave differently with modifiers, e.g. to add to a
+    /// selection with <kbd>Shift</kbd> held. It is analogous to
+    /// [`Button::on_press_with`] and replaces any message set with
+    /// [`Button::on_press`] or [`Button::on_press_with`], and vice versa; a
+    /// [`Button`] has a single press handler.
+    ///
+    /// The modifiers are the ones held when the press is produced, which is
+    /// on release for mouse and touch presses.
+    pub fn on_press_with_modifiers(
+        mut self,
+        on_press: impl Fn(keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_press = Some(OnPress::WithModifiers(Box::new(on_press)));
+        self
+    }
+
     /// Sets the message that will be produced when the [`Button`] is pressed,
     /// if `Some`.
     ///
@@ -187,6 +296,136 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Button`] is held
+    /// down for the given `duration`.
+    ///
+    /// Holding the [`Button`] long enough produces this message right away,
+    /// and the [`on_press`](Button::on_press) message is not produced when it
+    /// is released; a quick release still produces it as usual. While held,
+    /// a bar along the bottom of the [`Button`] shows the progress towards
+    /// the hold.
+    ///
+    /// This works for mouse and touch presses. Keyboard presses always
+    /// produce the [`on_press`](Button::on_press) message.
+    pub fn on_hold(mut self, duration: Duration, message: Message) -> Self {
+        self.on_hold = Some((duration, message));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is
+    /// long-pressed, e.g. to trigger a secondary action on touch devices.
+    ///
+    /// The message is produced as soon as a mouse or touch press lasts for
+    /// the [`long_press_duration`](Button::long_press_duration), and the
+    /// [`on_press`](Button::on_press) message is then not produced on
+    /// release. Unlike [`on_hold`](Button::on_hold), no progress is shown
+    /// while the [`Button`] is held; both can be set, with their own
+    /// durations.
+    ///
+    /// A long press does not count towards an
+    /// [`on_double_press`](Button::on_double_press): if the second press of
+    /// a double press is held long enough, only this message is produced.
+    pub fn on_long_press(mut self, on_long_press: Message) -> Self {
+        self.on_long_press = Some(on_long_press);
+        self
+    }
+
+    /// Sets how long the [`Button`] must be held down to be long-pressed.
+    ///
+    /// By default, it is 500 milliseconds. See
+    /// [`on_long_press`](Button::on_long_press).
+    pub fn long_press_duration(mut self, duration: Duration) -> Self {
+        self.long_press_duration = duration;
+        self
+    }
+
+    /// Makes the [`Button`] repeat its [`on_press`](Button::on_press) message
+    /// while held down, like a key of a keyboard.
+    ///
+    /// Once the [`Button`] has been held for `initial_delay`, the message is
+    /// produced right away and then every `interval`, until it is released
+    /// or the cursor leaves it. Releasing it after it repeated does not
+    /// produce the message once more; a quick release still produces it as
+    /// usual. Repeats are not debounced.
+    ///
+    /// This works for mouse and touch presses, and a disabled [`Button`]
+    /// never repeats.
+    pub fn repeat(
+        mut self,
+        initial_delay: Duration,
+        interval: Duration,
+    ) -> Self {
+        self.repeat = Some((initial_delay, interval));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// twice in quick succession.
+    ///
+    /// The first press produces the [`on_press`](Button::on_press) message as
+    /// usual, and the second one produces this message instead. Both presses
+    /// must happen within the
+    /// [`double_press_interval`](Button::double_press_interval), without the
+    /// cursor leaving the [`Button`] in between.
+    pub fn on_double_press(mut self, on_double_press: Message) -> Self {
+        self.on_double_press = Some(on_double_press);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button, e.g. to open a context menu.
+    ///
+    /// The press is produced on release, if the cursor is still over the
+    /// [`Button`]. It does not focus the [`Button`] nor make it look pressed,
+    /// and it does not enable a disabled [`Button`] by itself.
+    pub fn on_right_press(mut self, on_right_press: Message) -> Self {
+        self.on_right_press = Some(OnPress::Direct(on_right_press));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button.
+    ///
+    /// This is analogous to [`Button::on_right_press`], but using a closure
+    /// to produce the message.
+    pub fn on_right_press_with(
+        mut self,
+        on_right_press: impl Fn() -> Message + 'a,
+    ) -> Self {
+        self.on_right_press = Some(OnPress::Closure(Box::new(on_right_press)));
+        self
+    }
+
+    /// Sets the maximum time between the two presses of a double press of
+    /// the [`Button`].
+    ///
+    /// By default, it is 400 milliseconds. See
+    /// [`on_double_press`](Button::on_double_press).
+    pub fn double_press_interval(mut self, interval: Duration) -> Self {
+        self.double_press_interval = interval;
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor enters the
+    /// [`Button`].
+    ///
+    /// Unlike presses, this is produced even while the [`Button`] is
+    /// disabled; e.g. to show why it is disabled.
+    pub fn on_hover(mut self, on_hover: Message) -> Self {
+        self.on_hover = Some(on_hover);
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor leaves the
+    /// [`Button`].
+    ///
+    /// Like [`on_hover`](Button::on_hover), this is produced even while the
+    /// [`Button`] is disabled.
+    pub fn on_unhover(mut self, on_unhover: Message) -> Self {
+        self.on_unhover = Some(on_unhover);
+        self
+    }
+
     /// Sets the message that will be produced when the [`Button`] is focused.
     pub fn on_focus(mut self, on_focus: Message) -> Self {
         self.on_focus = Some(on_focus);
@@ -194,6 +433,9 @@ where
     }
 
     /// Sets the message that will be produced when the [`Button`] is blurred.
+    ///
+    /// This includes the window losing focus; see
+    /// [`blur_with_window`](Self::blur_with_window).
     pub fn on_blur(mut self, on_blur: Message) -> Self {
         self.on_blur = Some(on_blur);
         self
@@ -206,29 +448,418 @@ where
         self
     }
 
+    /// Sets the border radius of the [`Button`], overriding the one
+    /// produced by its style.
+    ///
+    /// This keeps the semantic style (e.g. [`primary`] or [`secondary`])
+    /// while changing only the shape of the [`Button`].
+    ///
+    /// Calling [`Button::style`] or [`Button::class`] afterwards discards
+    /// the override, so any radius set by the new style takes precedence
+    /// unless `radius` is called after it.
+    pub fn radius(mut self, radius: impl Into<border::Radius>) -> Self {
+        self.radius = Some(radius.into());
+        self
+    }
+
+    /// Animates the scale of the [`Button`] when it is hovered or pressed,
+    /// following the given [`ScaleSpec`].
+    ///
+    /// The [`Button`] is scaled around its center. Only its visuals are
+    /// scaled; hit-testing always uses its original bounds.
+    ///
+    /// By default, the [`Button`] is not animated.
+    pub fn scale_animation(mut self, scale_animation: ScaleSpec) -> Self {
+        self.scale_animation = Some(scale_animation);
+        self
+    }
+
+    /// Sets whether the [`Button`] is loading.
+    ///
+    /// A loading [`Button`] cannot be pressed nor focused, but keeps its
+    /// [`Button::on_press`] message for when it is done loading. Its status
+    /// is [`Status::Loading`], which styles can tell apart from
+    /// [`Status::Disabled`]. If [`Button::spinner_slot`] is enabled, a
+    /// spinner is shown while loading.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Sets the content shown in place of the regular content while the
+    /// [`Button`] is [`loading`](Button::loading); e.g. a "Sending…" label.
+    ///
+    /// The [`Button`] is still sized after its regular content, so it does
+    /// not resize when it starts or stops loading; the loading content is
+    /// centered within the same space.
+    pub fn loading_content(
+        mut self,
+        loading_content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.loading_content = Some(loading_content.into());
+        self
+    }
+
+    /// Sets whether the [`Button`] reserves a slot before its content for a
+    /// loading spinner.
+    ///
+    /// The spinner only appears while the [`Button`] is
+    /// [`loading`](Button::loading), drawn with the text color of its style.
+    /// Otherwise the slot stays empty, but its width is still reserved; so
+    /// the [`Button`] keeps the same size when it starts or stops loading.
+    pub fn spinner_slot(mut self, spinner_slot: bool) -> Self {
+        self.spinner_slot = spinner_slot;
+        self
+    }
+
+    /// Debounces the presses of the [`Button`] over the given quiet period.
+    ///
+    /// Presses closer together than `period` form a burst, and the
+    /// [`DebounceMode`] decides which presses of a burst produce the
+    /// [`on_press`](Button::on_press) message. By default, only the first one
+    /// does.
+    pub fn debounce(mut self, period: Duration) -> Self {
+        self.debounce = Some(period);
+        self
+    }
+
+    /// Sets the [`DebounceMode`] of the [`Button`].
+    ///
+    /// This has no effect unless the [`Button`] is
+    /// [`debounce`](Button::debounce)d.
+    pub fn debounce_mode(mut self, debounce_mode: DebounceMode) -> Self {
+        self.debounce_mode = debounce_mode;
+        self
+    }
+
+    /// Sets whether the panel or menu controlled by the [`Button`] is
+    /// expanded, or `None` if it does not control any.
+    ///
+    /// This does not change how the [`Button`] looks by default; themes can
+    /// style it through [`Catalog::expanded`]. `iced` has no accessibility
+    /// integration yet, so assistive technology cannot read it either; until
+    /// then, it is reported to the [`ui_snapshot`] operation, which is where
+    /// an accessibility backend should read it from.
+    ///
+    /// [`ui_snapshot`]: crate::widget::operation::ui_snapshot
+    pub fn expanded(mut self, expanded: Option<bool>) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Sets whether the content of the [`Button`] is underlined, with the
+    /// text color of its style.
+    ///
+    /// Together with the [`link`] style, this makes the [`Button`] look like
+    /// a hyperlink.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Button`] focuses itself when it first appears.
+    ///
+    /// The [`Button`] is focused with the first event it receives, producing
+    /// its [`on_focus`](Button::on_focus) message, unless it is disabled by
+    /// then. This happens once per [`Button`] in the widget tree; it is not
+    /// focused again when rebuilt by `view`.
+    ///
+    /// If several [`Button`]s autofocus at once, the last one in the widget
+    /// tree keeps the focus and the others are unfocused again, producing
+    /// their [`on_blur`](Button::on_blur) message.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    /// Sets whether clicking the [`Button`] focuses it.
+    ///
+    /// By default, it does. Turning it off is useful for toolbar buttons
+    /// that act on another focused widget, like a text editor: clicking them
+    /// still presses them, but leaves their focus untouched. They can still
+    /// be focused and pressed with the keyboard.
+    pub fn focus_on_click(mut self, focus_on_click: bool) -> Self {
+        self.focus_on_click = focus_on_click;
+        self
+    }
+
+    /// Sets whether a focused [`Button`] produces its
+    /// [`on_blur`](Self::on_blur) message when the window loses focus.
+    ///
+    /// By default, it does. The [`Button`] keeps its focus meanwhile, and
+    /// produces its [`on_focus`](Self::on_focus) message once the window is
+    /// focused again.
+    pub fn blur_with_window(mut self, blur_with_window: bool) -> Self {
+        self.blur_with_window = blur_with_window;
+        self
+    }
+
+    /// Sets the keys that press the [`Button`] while it is focused,
+    /// replacing the default <kbd>Enter</kbd> and <kbd>Space</kbd>.
+    ///
+    /// Modifiers are ignored, but character keys are compared as produced by
+    /// the keyboard; e.g. `"k"` does not match while <kbd>Shift</kbd> is
+    /// held, since the key is then `"K"`.
+    pub fn activation_keys(
+        mut self,
+        activation_keys: impl Into<Vec<keyboard::Key>>,
+    ) -> Self {
+        self.activation_keys = Some(activation_keys.into());
+        self
+    }
+
     /// Sets the style of the [`Button`].
+    ///
+    /// This discards any radius previously set with [`Button::radius`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
     where
         Theme::Class<'a>: From<StyleFn<'a, Theme>>,
     {
         self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self.radius = None;
         self
     }
 
     /// Sets the style class of the [`Button`].
+    ///
+    /// This discards any radius previously set with [`Button::radius`].
     #[must_use]
     pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
         self.class = class.into();
+        self.radius = None;
+        self
+    }
+
+    /// Returns whether the given key presses the [`Button`] while focused.
+    fn is_activation_key(&self, key: &keyboard::Key) -> bool {
+        match &self.activation_keys {
+            Some(activation_keys) => activation_keys.contains(key),
+            None => matches!(
+                key,
+                keyboard::Key::Named(
+                    keyboard::key::Named::Enter | keyboard::key::Named::Space
+                )
+            ),
+        }
+    }
+
+    fn is_enabled(&self, state: &State) -> bool {
+        (self.on_press.is_some()
+            || self.on_hold.is_some()
+            || self.on_long_press.is_some()
+            || self.on_double_press.is_some())
+            && !self.is_disabled
+            && !self.is_loading
+            && state.enabled.is_enabled()
+    }
+
+    /// Focuses the [`Button`] the first time it is operated on or updated,
+    /// if it has [`autofocus`](Self::autofocus); and unfocuses it again once
+    /// a later [`Button`] autofocuses.
+    fn sync_autofocus(&self, state: &mut State) {
+        if self.autofocus && !state.has_autofocused {
+            state.has_autofocused = true;
+
+            if self.is_enabled(state) {
+                state.is_focused = true;
+                state.autofocus =
+                    Some(AUTOFOCUS.fetch_add(1, Ordering::Relaxed) + 1);
+            }
+        }
+
+        if let Some(autofocus) = state.autofocus
+            && (!state.is_focused
+                || AUTOFOCUS.load(Ordering::Relaxed) != autofocus)
+        {
+            state.autofocus = None;
+            state.is_focused = false;
+        }
+    }
+
+    /// Publishes the message of a press of the [`Button`], unless it is
+    /// debounced.
+    fn press(&self, state: &mut State, shell: &mut Shell<'_, Message>)
+    where
+        Message: Clone,
+    {
+        let Some(on_press) = &self.on_press else {
+            return;
+        };
+
+        let Some(period) = self.debounce else {
+            shell.publish(on_press.get(state.modifiers));
+            return;
+        };
+
+        let now = Instant::now();
+        let is_bursting = state
+            .last_press
+            .is_some_and(|last_press| now.duration_since(last_press) < period);
+
+        state.last_press = Some(now);
+
+        let is_leading = match self.debounce_mode {
+            DebounceMode::Leading | DebounceMode::Both => !is_bursting,
+            DebounceMode::Trailing => false,
+        };
+
+        if is_leading {
+            shell.publish(on_press.get(state.modifiers));
+        } else if self.debounce_mode != DebounceMode::Leading {
+            state.is_press_pending = true;
+            shell.request_redraw_at(now + period);
+        }
+    }
+
+    /// Returns the [`Status`] of the [`Button`].
+    ///
+    /// Like in `iced`, a press looks pressed until it is released, even while
+    /// the cursor is dragged off the [`Button`]; releasing it there cancels
+    /// the press.
+    fn status(&self, state: &State, is_mouse_over: bool) -> Status {
+        if self.is_loading {
+            Status::Loading
+        } else if !self.is_enabled(state) {
+            Status::Disabled
+        } else if state.status == Status::Pressed {
+            Status::Pressed
+        } else if state.is_focused() {
+            Status::Focused {
+                is_hovered: is_mouse_over,
+            }
+        } else if is_mouse_over {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Button<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Theme: Catalog + container::Catalog + text_widget::Catalog + 'a,
+    Theme: theme::Base,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+{
+    /// Disables the [`Button`], optionally explaining why.
+    ///
+    /// Unlike leaving [`Button::on_press`] unset, this keeps the message
+    /// around while the [`Button`] is disabled; it is simply never produced.
+    /// The [`Button`] uses [`Status::Disabled`], cannot be focused, and
+    /// ignores presses.
+    ///
+    /// If a `reason` is given, it is shown in a tooltip while the [`Button`]
+    /// is hovered, in place of any [`tooltip`](Button::tooltip). The
+    /// [`Button`] is wrapped in a [`Tooltip`](iced_widget::Tooltip) even
+    /// without a reason, so it keeps its state when the reason changes.
+    pub fn disabled(mut self, reason: Option<String>) -> Self {
+        self.is_disabled = true;
+        self.disabled_tooltip = reason.map(|reason| {
+            container::Container::new(text_widget::Text::new(reason))
+                .padding([4, 8])
+                .style(tooltip_style)
+                .into()
+        });
+        self.wrap_in_tooltip = Some(wrap_in_tooltip);
+        self
+    }
+
+    /// Sets a tooltip shown next to the [`Button`] while it is hovered, on
+    /// the side given by the [`tooltip::Position`].
+    ///
+    /// The [`Button`] is wrapped in an `iced` [`Tooltip`](iced_widget::Tooltip)
+    /// when turned into an [`Element`], so the tooltip is placed the same way,
+    /// and kept within the window. It is shown even while the [`Button`] is
+    /// disabled, unless a reason was given to [`disabled`](Button::disabled),
+    /// which is shown instead. The tooltip is not interactive.
+    pub fn tooltip(
+        mut self,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+        position: tooltip::Position,
+    ) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self.tooltip_position = position;
+        self.wrap_in_tooltip = Some(wrap_in_tooltip);
         self
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Wraps the element of a [`Button`] in an `iced` [`Tooltip`](iced_widget::Tooltip)
+/// showing the given tooltip.
+///
+/// Showing a tooltip needs more of the theme and renderer than a [`Button`]
+/// does, so the builders that set one also store this function for
+/// [`Element::from`] to call.
+type WrapInTooltip<'a, Message, Theme, Renderer> =
+    fn(
+        Element<'a, Message, Theme, Renderer>,
+        Element<'a, Message, Theme, Renderer>,
+        tooltip::Position,
+    ) -> Element<'a, Message, Theme, Renderer>;
+
+fn wrap_in_tooltip<'a, Message, Theme, Renderer>(
+    content: Element<'a, Message, Theme, Renderer>,
+    tooltip: Element<'a, Message, Theme, Renderer>,
+    position: tooltip::Position,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: container::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    iced_widget::Tooltip::new(content, tooltip, position)
+        .gap(TOOLTIP_GAP)
+        .padding(0.0)
+        .into()
+}
+
+/// The style of the tooltip of a disabled [`Button`].
+fn tooltip_style<Theme>(theme: &Theme) -> container::Style
+where
+    Theme: theme::Base,
+{
+    let base = theme.base();
+
+    container::Style {
+        text_color: Some(base.text_color),
+        background: Some(Background::Color(base.background_color)),
+        border: Border {
+            color: base.text_color.scale_alpha(0.2),
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        ..container::Style::default()
+    }
+}
+
+#[derive(Debug, Clone)]
 struct State {
     is_focused: bool,
     was_focused: bool,
+    is_window_blurred: bool,
+    is_hovered: bool,
     status: Status,
+    enabled: EnabledState,
+    pending_press: PendingPress,
+    scale: Animation<f32>,
+    spinner_start: Instant,
+    last_press: Option<Instant>,
+    is_press_pending: bool,
+    hold_start: Option<Instant>,
+    is_held: bool,
+    is_long_pressed: bool,
+    repeat_at: Option<Instant>,
+    has_repeated: bool,
+    last_click: Option<Instant>,
+    is_right_pressed: bool,
+    has_autofocused: bool,
+    autofocus: Option<u64>,
+    modifiers: keyboard::Modifiers,
+    now: Instant,
 }
 
 impl Default for State {
@@ -236,7 +867,26 @@ impl Default for State {
         Self {
             is_focused: false,
             was_focused: false,
+            is_window_blurred: false,
+            is_hovered: false,
             status: Status::Active,
+            enabled: EnabledState::default(),
+            pending_press: PendingPress::default(),
+            scale: Animation::new(1.0),
+            spinner_start: Instant::now(),
+            last_press: None,
+            is_press_pending: false,
+            hold_start: None,
+            is_held: false,
+            is_long_pressed: false,
+            repeat_at: None,
+            has_repeated: false,
+            last_click: None,
+            is_right_pressed: false,
+            has_autofocused: false,
+            autofocus: None,
+            modifiers: keyboard::Modifiers::default(),
+            now: Instant::now(),
         }
     }
 }
@@ -270,15 +920,28 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::default())
+        let mut state = State::default();
+
+        if let Some(spec) = self.scale_animation {
+            state.scale = state.scale.duration(spec.duration);
+        }
+
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.content)]
+        std::iter::once(&self.content)
+            .chain(&self.loading_content)
+            .map(Tree::new)
+            .collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(std::slice::from_ref(&self.content));
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(&self.loading_content)
+            .collect();
+
+        tree.diff_children(&children);
     }
 
     fn size(&self) -> Size<Length> {
@@ -294,11 +957,18 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::padded(
+        let padding = if self.spinner_slot {
+            self.padding
+                .left(self.padding.left + SPINNER_SIZE + SPINNER_SPACING)
+        } else {
+            self.padding
+        };
+
+        let node = layout::padded(
             limits,
             self.width,
             self.height,
-            self.padding,
+            padding,
             |limits| {
                 self.content.as_widget_mut().layout(
                     &mut tree.children[0],
@@ -306,6 +976,28 @@ where
                     limits,
                 )
             },
+        );
+
+        let Some(loading_content) = &mut self.loading_content else {
+            return node;
+        };
+
+        // The loading content is centered within the regular content
+        let content_bounds = node.children()[0].bounds();
+
+        let loading_node = loading_content
+            .as_widget_mut()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &layout::Limits::new(Size::ZERO, content_bounds.size()),
+            )
+            .move_to(content_bounds.position())
+            .align(Alignment::Center, Alignment::Center, content_bounds.size());
+
+        layout::Node::with_children(
+            node.size(),
+            vec![node.children()[0].clone(), loading_node],
         )
     }
 
@@ -318,14 +1010,42 @@ where
     ) {
         let state = tree.state.downcast_mut::<State>();
 
+        operation.custom(self.id.as_ref(), layout.bounds(), &mut state.enabled);
+        operation.custom(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut state.pending_press,
+        );
+
         // Sync disabled status so Focusable::focus() can check it
-        if self.on_press.is_none() {
+        if !self.is_enabled(state) {
             state.status = Status::Disabled;
+            state.is_focused = false;
         } else if state.status == Status::Disabled {
             state.status = Status::Active;
         }
 
-        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        self.sync_autofocus(state);
+
+        // A disabled button is left out of focus traversal entirely, so
+        // focus_next skips it instead of landing nowhere
+        if state.status != Status::Disabled {
+            operation.custom(
+                self.id.as_ref(),
+                layout.bounds(),
+                &mut TabIndex(self.tab_index),
+            );
+            operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        }
+
+        operation.custom(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut WidgetState::Button {
+                status: self.status(state, state.is_hovered),
+                expanded: self.expanded,
+            },
+        );
 
         self.content.as_widget_mut().operate(
             &mut tree.children[0],
@@ -346,10 +1066,16 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        self.content.as_widget_mut().update(
-            &mut tree.children[0],
+        // Only the content being shown gets events, so it can animate
+        let (content, index) = match &mut self.loading_content {
+            Some(loading_content) if self.is_loading => (loading_content, 1),
+            _ => (&mut self.content, 0),
+        };
+
+        content.as_widget_mut().update(
+            &mut tree.children[index],
             event,
-            layout.children().next().unwrap(),
+            layout.children().nth(index).unwrap(),
             cursor,
             renderer,
             clipboard,
@@ -357,101 +1083,360 @@ where
             viewport,
         );
 
+        // Publish presses requested by operations, which cannot publish
+        // messages themselves
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            if state.pending_press.take() && self.is_enabled(state) {
+                self.press(state, shell);
+            }
+        }
+
         if shell.is_event_captured() {
             return;
         }
         // Detect focus changes from operations (e.g., Tab key)
         {
             let state = tree.state.downcast_mut::<State>();
+
+            self.sync_autofocus(state);
+
             if state.is_focused != state.was_focused {
                 if state.is_focused {
                     if let Some(on_focus) = &self.on_focus {
                         shell.publish(on_focus.clone());
                     }
-                } else if let Some(on_blur) = &self.on_blur {
+                } else if let Some(on_blur) = &self.on_blur
+                    && !state.is_window_blurred
+                {
                     shell.publish(on_blur.clone());
                 }
                 state.was_focused = state.is_focused;
+                state.is_window_blurred = false;
             }
         }
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
+                let state = tree.state.downcast_mut::<State>();
+
+                if self.is_enabled(state) {
                     let bounds = layout.bounds();
-                    let state = tree.state.downcast_mut::<State>();
 
                     if cursor.is_over(bounds) {
                         state.status = Status::Pressed;
 
-                        if let Some(on_focus) = &self.on_focus {
-                            if !state.is_focused() {
-                                shell.publish(on_focus.clone());
-                            }
+                        if self.on_hold.is_some()
+                            || self.on_long_press.is_some()
+                        {
+                            state.hold_start = Some(Instant::now());
+                            state.is_held = false;
+                            state.is_long_pressed = false;
+
+                            shell.request_redraw();
+                        }
+
+                        if let Some((initial_delay, _)) = self.repeat {
+                            let repeat_at = Instant::now() + initial_delay;
+
+                            state.repeat_at = Some(repeat_at);
+                            state.has_repeated = false;
+
+                            shell.request_redraw_at(repeat_at);
+                        }
+
+                        if self.focus_on_click {
+                            if let Some(on_focus) = &self.on_focus {
+                                if !state.is_focused() {
+                                    shell.publish(on_focus.clone());
+                                }
+                            }
+
+                            state.is_focused = true;
+                            state.was_focused = true;
+                        }
+
+                        shell.capture_event();
+                    } else {
+                        if let Some(on_blur) = &self.on_blur {
+                            if state.is_focused() {
+                                shell.publish(on_blur.clone());
+                            }
+                        }
+
+                        state.is_focused = false;
+                        state.was_focused = false;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                let is_held = state.is_held
+                    || state.is_long_pressed
+                    || state.has_repeated;
+
+                state.hold_start = None;
+                state.is_held = false;
+                state.is_long_pressed = false;
+                state.repeat_at = None;
+                state.has_repeated = false;
+
+                if state.status == Status::Pressed && self.is_enabled(state) {
+                    state.status = Status::Active;
+
+                    let bounds = layout.bounds();
+
+                    if cursor.is_over(bounds) && !is_held {
+                        let now = Instant::now();
+
+                        let is_double = state.last_click.is_some_and(|last| {
+                            now.duration_since(last)
+                                <= self.double_press_interval
+                        });
+
+                        if is_double
+                            && let Some(on_double_press) = &self.on_double_press
+                        {
+                            state.last_click = None;
+                            shell.publish(on_double_press.clone());
+                        } else {
+                            state.last_click = Some(now);
+                            self.press(state, shell);
+                        }
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if self.on_right_press.is_some()
+                    && self.is_enabled(state)
+                    && cursor.is_over(layout.bounds())
+                {
+                    state.is_right_pressed = true;
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Right,
+            )) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_right_pressed {
+                    state.is_right_pressed = false;
+
+                    if self.is_enabled(state)
+                        && cursor.is_over(layout.bounds())
+                        && let Some(on_right_press) = &self.on_right_press
+                    {
+                        shell.publish(on_right_press.get(state.modifiers));
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.status = Status::Active;
+                state.hold_start = None;
+                state.is_held = false;
+                state.is_long_pressed = false;
+                state.repeat_at = None;
+                state.has_repeated = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_focused()
+                    && self.is_activation_key(key)
+                    && self.is_enabled(state)
+                {
+                    state.status = Status::Pressed;
+                    self.press(state, shell);
+
+                    shell.capture_event();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased { key, .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                // A keyboard press only looks pressed until the key is
+                // released, or it would outrank the focus from then on
+                if state.status == Status::Pressed
+                    && self.is_activation_key(key)
+                {
+                    state.status = Status::Active;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.modifiers = *modifiers;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+                let is_hovered = cursor.is_over(layout.bounds());
+
+                if state.is_hovered != is_hovered {
+                    state.is_hovered = is_hovered;
+
+                    let on_hover = if is_hovered {
+                        &self.on_hover
+                    } else {
+                        &self.on_unhover
+                    };
+
+                    if let Some(on_hover) = on_hover {
+                        shell.publish(on_hover.clone());
+                    }
+
+                    // Leaving the button breaks a double press and stops
+                    // repeating
+                    if !is_hovered {
+                        state.last_click = None;
+                        state.repeat_at = None;
+                    }
+                }
+            }
+            Event::Window(window::Event::Unfocused) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if self.blur_with_window
+                    && state.is_focused
+                    && !state.is_window_blurred
+                {
+                    state.is_window_blurred = true;
+
+                    if let Some(on_blur) = &self.on_blur {
+                        shell.publish(on_blur.clone());
+                    }
+                }
+            }
+            Event::Window(window::Event::Focused) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if state.is_window_blurred {
+                    state.is_window_blurred = false;
+
+                    if let Some(on_focus) = &self.on_focus {
+                        shell.publish(on_focus.clone());
+                    }
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.now = *now;
+
+                if self.is_loading && self.spinner_slot {
+                    shell.request_redraw();
+                }
+
+                if let Some((_, interval)) = self.repeat
+                    && let Some(repeat_at) = state.repeat_at
+                    && state.status == Status::Pressed
+                    && self.is_enabled(state)
+                    && cursor.is_over(layout.bounds())
+                {
+                    if *now >= repeat_at {
+                        state.repeat_at = Some(*now + interval);
+                        state.has_repeated = true;
+
+                        if let Some(on_press) = &self.on_press {
+                            shell.publish(on_press.get(state.modifiers));
                         }
 
-                        state.is_focused = true;
-                        state.was_focused = true;
-
-                        shell.capture_event();
+                        shell.request_redraw_at(*now + interval);
                     } else {
-                        if let Some(on_blur) = &self.on_blur {
-                            if state.is_focused() {
-                                shell.publish(on_blur.clone());
-                            }
-                        }
+                        shell.request_redraw_at(repeat_at);
+                    }
+                }
 
-                        state.is_focused = false;
-                        state.was_focused = false;
+                if let Some((duration, on_hold)) = &self.on_hold
+                    && let Some(hold_start) = state.hold_start
+                    && !state.is_held
+                    && state.status == Status::Pressed
+                    && cursor.is_over(layout.bounds())
+                {
+                    if now.duration_since(hold_start) >= *duration {
+                        state.is_held = true;
+                        state.last_click = None;
+                        shell.publish(on_hold.clone());
+                    } else {
+                        shell.request_redraw();
                     }
                 }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if let Some(on_press) = self.on_press.as_ref().map(OnPress::get)
+
+                if let Some(on_long_press) = &self.on_long_press
+                    && let Some(hold_start) = state.hold_start
+                    && !state.is_long_pressed
+                    && state.status == Status::Pressed
+                    && cursor.is_over(layout.bounds())
                 {
-                    let state = tree.state.downcast_mut::<State>();
+                    let long_press_at = hold_start + self.long_press_duration;
 
-                    if state.status == Status::Pressed {
-                        state.status = Status::Active;
+                    if *now >= long_press_at {
+                        state.is_long_pressed = true;
+                        state.last_click = None;
+                        shell.publish(on_long_press.clone());
+                    } else {
+                        shell.request_redraw_at(long_press_at);
+                    }
+                }
 
-                        let bounds = layout.bounds();
+                if state.is_press_pending
+                    && let Some(period) = self.debounce
+                    && let Some(last_press) = state.last_press
+                {
+                    let deadline = last_press + period;
 
-                        if cursor.is_over(bounds) {
-                            shell.publish(on_press);
-                        }
+                    if *now < deadline {
+                        shell.request_redraw_at(deadline);
+                    } else {
+                        state.is_press_pending = false;
 
-                        shell.capture_event();
+                        if self.is_enabled(state)
+                            && let Some(on_press) = &self.on_press
+                        {
+                            shell.publish(on_press.get(state.modifiers));
+                        }
                     }
                 }
             }
-            Event::Touch(touch::Event::FingerLost { .. }) => {
-                let state = tree.state.downcast_mut::<State>();
+            _ => {}
+        }
 
-                state.status = Status::Active;
+        let is_mouse_over = cursor.is_over(layout.bounds());
+        let state = tree.state.downcast_mut::<State>();
+        let status = self.status(state, is_mouse_over);
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.last_status = Some(status);
+        } else if self
+            .last_status
+            .is_some_and(|last_status| last_status != status)
+        {
+            shell.request_redraw();
+        }
+
+        if let Some(spec) = self.scale_animation {
+            let scale = spec.scale(status);
+
+            if state.scale.value() != scale {
+                state.scale.go_mut(scale, Instant::now());
             }
-            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
-                if let Some(on_press) = self.on_press.as_ref() {
-                    let state = tree.state.downcast_mut::<State>();
-
-                    if state.is_focused()
-                        && matches!(
-                            key,
-                            keyboard::Key::Named(
-                                keyboard::key::Named::Enter
-                                    | keyboard::key::Named::Space
-                            )
-                        )
-                    {
-                        state.status = Status::Pressed;
-                        shell.publish(on_press.get());
 
-                        shell.capture_event();
-                    }
-                }
+            if state.scale.is_animating(state.now) {
+                shell.request_redraw();
             }
-            _ => {}
         }
     }
 
@@ -469,40 +1454,16 @@ where
         let content_layout = layout.children().next().unwrap();
         let is_mouse_over = cursor.is_over(bounds);
         let state = tree.state.downcast_ref::<State>();
+        let status = self.status(state, is_mouse_over);
 
-        let status = if self.on_press.is_none() {
-            Status::Disabled
-        } else if state.is_focused() {
-            Status::Focused {
-                is_hovered: is_mouse_over,
-            }
-        } else if is_mouse_over {
-            if state.status == Status::Pressed {
-                Status::Pressed
-            } else {
-                Status::Hovered
-            }
+        let mut style = if self.expanded == Some(true) {
+            theme.expanded(&self.class, status)
         } else {
-            Status::Active
+            theme.style(&self.class, status)
         };
 
-        let style = theme.style(&self.class, status);
-
-        if style.background.is_some()
-            || style.border.width > 0.0
-            || style.shadow.color.a > 0.0
-        {
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border: style.border,
-                    shadow: style.shadow,
-                    snap: true,
-                },
-                style
-                    .background
-                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
-            );
+        if let Some(radius) = self.radius {
+            style.border.radius = radius;
         }
 
         let viewport = if self.clip {
@@ -511,22 +1472,132 @@ where
             *viewport
         };
 
-        self.content.as_widget().draw(
-            &tree.children[0],
-            renderer,
-            theme,
-            &renderer::Style {
-                text_color: style.text_color,
-            },
-            content_layout,
-            cursor,
-            &viewport,
-        );
+        let draw = |renderer: &mut Renderer| {
+            if style.background.is_some()
+                || style.border.width > 0.0
+                || style.shadow.color.a > 0.0
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        shadow: style.shadow,
+                        snap: true,
+                    },
+                    style
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            match &self.loading_content {
+                Some(loading_content) if self.is_loading => {
+                    loading_content.as_widget().draw(
+                        &tree.children[1],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.text_color,
+                        },
+                        layout.children().nth(1).unwrap(),
+                        cursor,
+                        &bounds.intersection(&viewport).unwrap_or(viewport),
+                    );
+                }
+                _ => {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.text_color,
+                        },
+                        content_layout,
+                        cursor,
+                        &viewport,
+                    );
+                }
+            }
+
+            if self.underline {
+                let content_bounds = content_layout.bounds();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: content_bounds.y + content_bounds.height
+                                - UNDERLINE_WIDTH,
+                            height: UNDERLINE_WIDTH,
+                            ..content_bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.text_color,
+                );
+            }
+
+            if let Some((duration, _)) = &self.on_hold
+                && let Some(hold_start) = state.hold_start
+                && !state.is_held
+                && status == Status::Pressed
+            {
+                let progress = state
+                    .now
+                    .saturating_duration_since(hold_start)
+                    .as_secs_f32()
+                    / duration.as_secs_f32().max(f32::EPSILON);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: bounds.y + bounds.height - HOLD_PROGRESS_HEIGHT,
+                            width: bounds.width * progress.min(1.0),
+                            height: HOLD_PROGRESS_HEIGHT,
+                            ..bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.text_color.scale_alpha(0.5),
+                );
+            }
+
+            if self.is_loading && self.spinner_slot {
+                let content_bounds = content_layout.bounds();
+
+                draw_spinner(
+                    renderer,
+                    Point::new(
+                        content_bounds.x - SPINNER_SPACING - SPINNER_SIZE / 2.0,
+                        content_bounds.center_y(),
+                    ),
+                    state.now.saturating_duration_since(state.spinner_start),
+                    style.text_color,
+                );
+            }
+        };
+
+        let scale = if self.scale_animation.is_some() {
+            state.scale.interpolate_with(|scale| scale, state.now)
+        } else {
+            1.0
+        };
+
+        if scale == 1.0 {
+            draw(renderer);
+        } else {
+            let center = bounds.center();
+            let scaling = Transformation::scale(scale);
+            let translation = center - center * scaling;
+
+            renderer.with_translation(translation, |renderer| {
+                renderer.with_transformation(scaling, draw);
+            });
+        }
     }
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
@@ -534,10 +1605,14 @@ where
     ) -> mouse::Interaction {
         let is_mouse_over = cursor.is_over(layout.bounds());
 
-        if is_mouse_over && self.on_press.is_some() {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !is_mouse_over {
+            mouse::Interaction::default()
+        } else if self.is_enabled(state) {
             mouse::Interaction::Pointer
         } else {
-            mouse::Interaction::default()
+            mouse::Interaction::NotAllowed
         }
     }
 
@@ -566,11 +1641,115 @@ where
     Theme: Catalog + 'a,
     Renderer: crate::core::Renderer + 'a,
 {
-    fn from(button: Button<'a, Message, Theme, Renderer>) -> Self {
-        Self::new(button)
+    fn from(mut button: Button<'a, Message, Theme, Renderer>) -> Self {
+        let Some(wrap_in_tooltip) = button.wrap_in_tooltip else {
+            return Self::new(button);
+        };
+
+        // An empty tooltip keeps the widget tree the same, and with it the
+        // state of the button, when the tooltip or reason comes and goes
+        let tooltip = button
+            .disabled_tooltip
+            .take()
+            .or_else(|| button.tooltip.take())
+            .unwrap_or_else(|| Space::new().into());
+        let position = button.tooltip_position;
+
+        wrap_in_tooltip(Self::new(button), tooltip, position)
+    }
+}
+
+/// The number of [`Button`]s that autofocused so far; the last one keeps
+/// the focus.
+static AUTOFOCUS: AtomicU64 = AtomicU64::new(0);
+
+/// The default maximum time between the two presses of a double press of a
+/// [`Button`].
+const DOUBLE_PRESS_INTERVAL: Duration = Duration::from_millis(400);
+
+/// The default time a [`Button`] must be held down to be long-pressed.
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// The gap between a [`Button`] and its tooltip.
+const TOOLTIP_GAP: f32 = 4.0;
+
+/// The height of the bar showing the progress of a hold of a [`Button`].
+const HOLD_PROGRESS_HEIGHT: f32 = 3.0;
+
+/// The size of the loading spinner of a [`Button`].
+const SPINNER_SIZE: f32 = 16.0;
+
+/// The space between the loading spinner and the content of a [`Button`].
+const SPINNER_SPACING: f32 = 8.0;
+
+/// The amount of dots of the loading spinner of a [`Button`].
+const SPINNER_DOTS: usize = 8;
+
+/// The time it takes the loading spinner of a [`Button`] to complete a turn.
+const SPINNER_PERIOD: Duration = Duration::from_millis(800);
+
+/// Draws a loading spinner centered at the given point, as a ring of dots
+/// that fade out behind the leading one.
+fn draw_spinner<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    elapsed: Duration,
+    color: Color,
+) where
+    Renderer: crate::core::Renderer,
+{
+    const DOT_SIZE: f32 = 3.0;
+
+    let turns = elapsed.as_secs_f32() / SPINNER_PERIOD.as_secs_f32();
+    let leading = (turns.fract() * SPINNER_DOTS as f32) as usize;
+    let radius = (SPINNER_SIZE - DOT_SIZE) / 2.0;
+
+    for i in 0..SPINNER_DOTS {
+        let angle = i as f32 / SPINNER_DOTS as f32 * std::f32::consts::TAU;
+        let distance = (leading + SPINNER_DOTS - i) % SPINNER_DOTS;
+        let alpha = 1.0 - distance as f32 / SPINNER_DOTS as f32;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x + radius * angle.cos() - DOT_SIZE / 2.0,
+                    y: center.y + radius * angle.sin() - DOT_SIZE / 2.0,
+                    width: DOT_SIZE,
+                    height: DOT_SIZE,
+                },
+                border: border::rounded(DOT_SIZE / 2.0),
+                ..renderer::Quad::default()
+            },
+            color.scale_alpha(alpha),
+        );
     }
 }
 
+/// Which presses of a burst produce the message of a debounced [`Button`].
+///
+/// A burst is a run of presses, each closer than the debounce period to the
+/// previous one; it ends once the [`Button`] is left alone for a whole
+/// period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DebounceMode {
+    /// The first press produces the message right away, and the rest of the
+    /// burst is ignored.
+    #[default]
+    Leading,
+    /// Presses produce nothing while bursting; the message is produced once,
+    /// a whole period after the last press.
+    Trailing,
+    /// The first press produces the message right away and, if it was
+    /// pressed again during the burst, the message is produced once more
+    /// when the burst ends.
+    Both,
+}
+
+/// The thickness of the line drawn by an [`underline`]d [`Button`].
+///
+/// [`underline`]: Button::underline
+const UNDERLINE_WIDTH: f32 = 1.0;
+
 /// The default [`Padding`] of a [`Button`].
 pub(crate) const DEFAULT_PADDING: Padding = Padding {
     top: 5.0,
@@ -581,6 +1760,7 @@ pub(crate) const DEFAULT_PADDING: Padding = Padding {
 
 /// The possible status of a [`Button`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Status {
     /// The [`Button`] can be pressed.
     Active,
@@ -595,6 +1775,50 @@ pub enum Status {
     },
     /// The [`Button`] cannot be pressed.
     Disabled,
+    /// The [`Button`] is [`loading`](Button::loading), so it cannot be
+    /// pressed until it is done.
+    ///
+    /// The built-in styles draw it like [`Status::Disabled`].
+    Loading,
+}
+
+/// The scale animation of a [`Button`].
+///
+/// Each scale is relative to the original size of the [`Button`], where
+/// `1.0` leaves it untouched.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaleSpec {
+    /// The scale of the [`Button`] when hovered.
+    pub hovered: f32,
+    /// The scale of the [`Button`] when pressed.
+    pub pressed: f32,
+    /// The duration of the transition between scales.
+    pub duration: Duration,
+}
+
+impl ScaleSpec {
+    fn scale(&self, status: Status) -> f32 {
+        match status {
+            Status::Hovered | Status::Focused { is_hovered: true } => {
+                self.hovered
+            }
+            Status::Pressed => self.pressed,
+            Status::Active
+            | Status::Focused { is_hovered: false }
+            | Status::Disabled
+            | Status::Loading => 1.0,
+        }
+    }
+}
+
+impl Default for ScaleSpec {
+    fn default() -> Self {
+        Self {
+            hovered: 1.02,
+            pressed: 0.96,
+            duration: Duration::from_millis(100),
+        }
+    }
 }
 
 /// A styling function for a [`Button`].
@@ -612,6 +1836,14 @@ pub trait Catalog {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`Style`] of a class with the given status, while the panel or
+    /// menu it controls is [`expanded`](Button::expanded).
+    ///
+    /// By default, this is the same as [`Catalog::style`].
+    fn expanded(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        self.style(class, status)
+    }
 }
 
 impl Catalog for crate::Theme {
@@ -650,7 +1882,7 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             },
             ..base
         },
-        Status::Disabled => disabled(base),
+        Status::Disabled | Status::Loading => disabled(base),
     }
 }
 
@@ -678,7 +1910,7 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
             },
             ..base
         },
-        Status::Disabled => disabled(base),
+        Status::Disabled | Status::Loading => disabled(base),
     }
 }
 
@@ -706,7 +1938,7 @@ pub fn success(theme: &Theme, status: Status) -> Style {
             },
             ..base
         },
-        Status::Disabled => disabled(base),
+        Status::Disabled | Status::Loading => disabled(base),
     }
 }
 
@@ -734,7 +1966,7 @@ pub fn danger(theme: &Theme, status: Status) -> Style {
             },
             ..base
         },
-        Status::Disabled => disabled(base),
+        Status::Disabled | Status::Loading => disabled(base),
     }
 }
 
@@ -768,7 +2000,64 @@ pub fn text(theme: &Theme, status: Status) -> Style {
             },
             ..base
         },
-        Status::Disabled => disabled(base),
+        Status::Disabled | Status::Loading => disabled(base),
+    }
+}
+
+/// A link button; looking like a hyperlink when [`underline`]d.
+///
+/// [`underline`]: Button::underline
+pub fn link(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    linked(
+        palette.primary.base.color,
+        palette.primary.strong.color,
+        status,
+    )
+}
+
+/// A link button that has already been visited.
+///
+/// Its color is between the primary and danger colors of the [`Theme`];
+/// purple, for the usual blue and red.
+pub fn link_visited(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let mix = |a: Color, b: Color| {
+        Color::from_rgb((a.r + b.r) / 2.0, (a.g + b.g) / 2.0, (a.b + b.b) / 2.0)
+    };
+
+    linked(
+        mix(palette.primary.base.color, palette.danger.base.color),
+        mix(palette.primary.strong.color, palette.danger.strong.color),
+        status,
+    )
+}
+
+fn linked(color: Color, hovered: Color, status: Status) -> Style {
+    let base = Style {
+        text_color: color,
+        border: border::rounded(2),
+        ..Style::default()
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered | Status::Pressed => Style {
+            text_color: hovered,
+            ..base
+        },
+        Status::Focused { is_hovered } => Style {
+            text_color: if is_hovered { hovered } else { color },
+            border: Border {
+                color,
+                width: 1.0,
+                ..base.border
+            },
+            ..base
+        },
+        Status::Disabled | Status::Loading => disabled(base),
     }
 }
 
@@ -802,7 +2091,7 @@ pub fn background(theme: &Theme, status: Status) -> Style {
             },
             ..base
         },
-        Status::Disabled => disabled(base),
+        Status::Disabled | Status::Loading => disabled(base),
     }
 }
 
@@ -836,7 +2125,7 @@ pub fn subtle(theme: &Theme, status: Status) -> Style {
             },
             ..base
         },
-        Status::Disabled => disabled(base),
+        Status::Disabled | Status::Loading => disabled(base),
     }
 }
 
@@ -858,3 +2147,296 @@ fn disabled(style: Style) -> Style {
         ..style
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Point;
+    use crate::test::Harness;
+    use crate::widget::operation::{self, WidgetState};
+
+    use iced_widget::text;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Pressed,
+        Held,
+        LongPressed,
+        RightPressed,
+        Focused,
+        Blurred,
+        Selected { extend: bool },
+    }
+
+    fn button() -> Button<'static, Message, crate::Theme, ()> {
+        Button::new(text("Press me"))
+            .id("button")
+            .width(100)
+            .height(30)
+            .on_press(Message::Pressed)
+    }
+
+    fn harness() -> Harness<'static, Message> {
+        Harness::new(button(), Size::new(200.0, 200.0))
+    }
+
+    fn press_for(harness: &mut Harness<'_, Message>, duration: Duration) {
+        harness.move_cursor(Point::new(50.0, 15.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        harness.redraw(Instant::now() + duration);
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+    }
+
+    fn status(harness: &mut Harness<'_, Message>) -> Status {
+        let snapshot = harness.run(operation::ui_snapshot()).pop().unwrap();
+
+        match snapshot.get(&Id::new("button")).unwrap().state {
+            Some(WidgetState::Button { status, .. }) => status,
+            ref state => panic!("unexpected state: {state:?}"),
+        }
+    }
+
+    #[test]
+    fn press_dragged_off_and_released_is_canceled() {
+        let mut harness = harness();
+
+        harness.move_cursor(Point::new(50.0, 15.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+
+        assert_eq!(status(&mut harness), Status::Pressed);
+
+        harness.move_cursor(Point::new(150.0, 150.0));
+
+        assert_eq!(status(&mut harness), Status::Pressed);
+
+        harness.move_cursor(Point::new(150.0, 150.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        assert!(harness.messages().is_empty());
+
+        harness.move_cursor(Point::new(50.0, 15.0));
+
+        assert!(!matches!(status(&mut harness), Status::Pressed));
+    }
+
+    #[test]
+    fn keyboard_press_does_not_stick() {
+        let mut harness = harness();
+
+        let _ = harness.run::<()>(operation::focus_next_by_index());
+        harness.tap_key(keyboard::key::Named::Enter);
+
+        assert_eq!(harness.messages(), vec![Message::Pressed]);
+
+        harness.move_cursor(Point::new(50.0, 15.0));
+
+        assert_eq!(status(&mut harness), Status::Focused { is_hovered: true });
+    }
+
+    #[test]
+    fn long_press_replaces_the_press() {
+        let button = button().on_long_press(Message::LongPressed);
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        press_for(&mut harness, Duration::from_millis(600));
+
+        assert_eq!(harness.messages(), vec![Message::LongPressed]);
+
+        press_for(&mut harness, Duration::from_millis(100));
+
+        assert_eq!(harness.messages(), vec![Message::Pressed]);
+    }
+
+    #[test]
+    fn long_press_and_hold_have_their_own_durations() {
+        let button = button()
+            .on_hold(Duration::from_secs(2), Message::Held)
+            .on_long_press(Message::LongPressed)
+            .long_press_duration(Duration::from_secs(1));
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        press_for(&mut harness, Duration::from_millis(1500));
+
+        assert_eq!(harness.messages(), vec![Message::LongPressed]);
+
+        press_for(&mut harness, Duration::from_millis(2500));
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::Held, Message::LongPressed]
+        );
+    }
+
+    #[test]
+    fn tooltip_wraps_the_button_without_hiding_it() {
+        let button = button().tooltip(text("Tooltip"), tooltip::Position::Top);
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        harness.click(Point::new(50.0, 15.0));
+
+        assert_eq!(harness.messages(), vec![Message::Pressed]);
+        assert_eq!(status(&mut harness), Status::Focused { is_hovered: true });
+    }
+
+    #[test]
+    fn disabled_reason_keeps_the_widget_tree() {
+        let tag = |button: Button<'static, Message, crate::Theme, ()>| {
+            let element = Element::from(button);
+            let tree = Tree::new(&element);
+
+            (tree.tag, tree.children.first().map(|child| child.tag))
+        };
+
+        let without_reason = tag(button().disabled(None));
+
+        assert_eq!(
+            tag(button().disabled(Some(String::from("Busy")))),
+            without_reason
+        );
+        assert_eq!(
+            tag(button().tooltip(text("Tooltip"), tooltip::Position::Top)),
+            without_reason
+        );
+    }
+
+    #[test]
+    fn right_press_is_independent_of_left_press() {
+        let button = button().on_right_press(Message::RightPressed);
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        harness.move_cursor(Point::new(50.0, 15.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Right,
+        )));
+
+        assert_eq!(status(&mut harness), Status::Hovered);
+
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Right,
+        )));
+
+        assert_eq!(harness.messages(), vec![Message::RightPressed]);
+        assert_eq!(status(&mut harness), Status::Hovered);
+
+        harness.click(Point::new(50.0, 15.0));
+
+        assert_eq!(harness.messages(), vec![Message::Pressed]);
+    }
+
+    #[test]
+    fn loading_button_does_not_publish_presses() {
+        let button = button().loading(true);
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        harness.click(Point::new(50.0, 15.0));
+
+        assert!(harness.messages().is_empty());
+        assert_eq!(status(&mut harness), Status::Loading);
+    }
+
+    #[test]
+    fn disabled_button_shows_a_not_allowed_cursor() {
+        let button: Button<'_, Message, crate::Theme, ()> =
+            Button::new(text("Press me")).width(100).height(30);
+        let mut disabled = Harness::new(button, Size::new(200.0, 200.0));
+
+        disabled.move_cursor(Point::new(50.0, 15.0));
+
+        assert_eq!(
+            disabled.mouse_interaction(),
+            mouse::Interaction::NotAllowed
+        );
+
+        disabled.move_cursor(Point::new(150.0, 150.0));
+
+        assert_eq!(disabled.mouse_interaction(), mouse::Interaction::None);
+
+        let mut enabled = harness();
+
+        enabled.move_cursor(Point::new(50.0, 15.0));
+
+        assert_eq!(enabled.mouse_interaction(), mouse::Interaction::Pointer);
+    }
+
+    #[test]
+    fn click_does_not_focus_without_focus_on_click() {
+        let button = button().on_focus(Message::Focused).focus_on_click(false);
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        harness.click(Point::new(50.0, 15.0));
+
+        assert_eq!(harness.messages(), vec![Message::Pressed]);
+        assert_eq!(harness.run(operation::focused()), vec![None]);
+
+        // Keyboard activation still works once focused with Tab
+        let _ = harness.run::<()>(operation::focus_next_by_index());
+        harness.tap_key(keyboard::key::Named::Enter);
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::Focused, Message::Pressed]
+        );
+    }
+
+    #[test]
+    fn press_receives_the_held_modifiers() {
+        let button =
+            button().on_press_with_modifiers(|modifiers| Message::Selected {
+                extend: modifiers.shift(),
+            });
+        let mut harness = Harness::new(button, Size::new(200.0, 200.0));
+
+        harness.click(Point::new(50.0, 15.0));
+        let _ = harness.set_modifiers(keyboard::Modifiers::SHIFT);
+        harness.click(Point::new(50.0, 15.0));
+
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::Selected { extend: false },
+                Message::Selected { extend: true },
+            ]
+        );
+    }
+
+    #[test]
+    fn last_autofocus_button_keeps_the_focus() {
+        let autofocus = |id: &'static str| {
+            button()
+                .id(id)
+                .autofocus(true)
+                .on_focus(Message::Focused)
+                .on_blur(Message::Blurred)
+        };
+
+        let mut harness = Harness::new(
+            iced_widget::column![autofocus("first"), autofocus("second")],
+            Size::new(200.0, 200.0),
+        );
+
+        harness.move_cursor(Point::new(150.0, 150.0));
+        harness.move_cursor(Point::new(150.0, 160.0));
+
+        let snapshot = harness.run(operation::ui_snapshot()).pop().unwrap();
+        let focused: Vec<_> = snapshot
+            .widgets()
+            .iter()
+            .filter(|widget| widget.is_focused == Some(true))
+            .map(|widget| widget.id.clone())
+            .collect();
+
+        assert_eq!(focused, vec![Id::new("second")]);
+        assert_eq!(
+            harness.messages(),
+            vec![Message::Focused, Message::Focused, Message::Blurred]
+        );
+    }
+}
@@ -1,13 +1,17 @@
 //! Drag-and-drop support for [`Row`] and [`Column`] widgets.
 //!
 //! This module provides types for handling drag-and-drop reordering of items
-//! within [`Row`] and [`Column`] containers.
+//! within [`Row`] and [`Column`] containers, and for moving items between
+//! containers that share a drag session through their drag groups.
 //!
 //! [`Row`]: super::Row
 //! [`Column`]: super::Column
 
+use std::sync::Mutex;
+use std::sync::atomic::{self, AtomicU64};
+
 /// Events emitted during drag operations.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DragEvent {
     /// An item was picked up and drag started.
     Picked {
@@ -27,3 +31,315 @@ pub enum DragEvent {
         index: usize,
     },
 }
+
+/// How a [`Row`] or [`Column`] shows where a dragged item will be dropped.
+///
+/// [`Row`]: super::Row
+/// [`Column`]: super::Column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropPreview {
+    /// The items between the dragged item and its drop position move apart
+    /// to make room for it.
+    #[default]
+    MakeRoom,
+    /// The items stay in place and a line is drawn where the dragged item
+    /// will be dropped.
+    Line,
+}
+
+/// How a [`Row`] or [`Column`] picks where a dragged item will be dropped.
+///
+/// [`Row`]: super::Row
+/// [`Column`]: super::Column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropZones {
+    /// The dragged item takes the place of the item under the cursor, as soon
+    /// as the cursor crosses into it.
+    #[default]
+    Midpoint,
+    /// Each item is split in halves: the dragged item is dropped before the
+    /// item under the cursor when in its first half, and after it otherwise.
+    HalfItem,
+}
+
+/// The identifier of a drop target of a [`Row`] or [`Column`].
+///
+/// [`Row`]: super::Row
+/// [`Column`]: super::Column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TargetId(&'static str);
+
+impl TargetId {
+    /// Creates a new [`TargetId`] with the given name.
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    /// Returns the name of the [`TargetId`].
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// An item dragged out of a [`Row`] or [`Column`] and dropped onto another
+/// one with a drag group.
+///
+/// [`Row`]: super::Row
+/// [`Column`]: super::Column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DragTransfer {
+    /// The drag group of the container the item was dragged out of.
+    pub source_group: String,
+    /// Index of the dragged item in its container.
+    pub source_index: usize,
+    /// The drag group of the container the item was dropped onto.
+    pub target_group: String,
+    /// Index of the position the item was dropped at in its new container,
+    /// between `0` and the number of items there.
+    pub target_index: usize,
+}
+
+/// The drag of an item that may leave its container, shared by every
+/// container with a drag group.
+///
+/// Only one item can be dragged with the pointer at a time, so there is a
+/// single session at most, shared by the whole process: containers do not
+/// know their window, so they cannot keep the drags of different windows
+/// apart. Containers are told apart by a key unique to their widget state.
+///
+/// The source container ends the session when the item is dropped. If it
+/// left the widget tree during the drag, the session is discarded once the
+/// release has been seen by any other container instead.
+#[derive(Debug)]
+pub(crate) struct Session {
+    source: u64,
+    group: String,
+    index: usize,
+    target: Option<(u64, String, usize)>,
+    is_released: bool,
+}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+impl Session {
+    /// Returns a new key to take part in drag sessions with.
+    pub(crate) fn next_key() -> u64 {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+
+        NEXT.fetch_add(1, atomic::Ordering::Relaxed)
+    }
+
+    /// Starts the drag of the item at `index` of the `source` container.
+    pub(crate) fn start(source: u64, group: &str, index: usize) {
+        *lock() = Some(Session {
+            source,
+            group: group.to_owned(),
+            index,
+            target: None,
+            is_released: false,
+        });
+    }
+
+    /// Updates whether the `member` container is under the dragged item,
+    /// which would be dropped at `target_index`, returning whether an item
+    /// of another container is being dragged.
+    pub(crate) fn hover(
+        member: u64,
+        group: &str,
+        target_index: Option<usize>,
+    ) -> bool {
+        let mut session = lock();
+
+        if session.as_ref().is_some_and(|s| s.is_released) {
+            *session = None;
+        }
+
+        let Some(session) = session.as_mut().filter(|s| s.source != member)
+        else {
+            return false;
+        };
+
+        match target_index {
+            Some(index) => {
+                session.target = Some((member, group.to_owned(), index));
+            }
+            None => {
+                if session
+                    .target
+                    .as_ref()
+                    .is_some_and(|(target, ..)| *target == member)
+                {
+                    session.target = None;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Marks the session as released, once the pointer button is released.
+    ///
+    /// The source container ends a released session right away; otherwise,
+    /// it is stale and the next [`Session::hover`] discards it.
+    pub(crate) fn release() {
+        if let Some(session) = lock().as_mut() {
+            session.is_released = true;
+        }
+    }
+
+    /// Returns whether the item dragged out of the `source` container is
+    /// over another container.
+    pub(crate) fn is_transferring(source: u64) -> bool {
+        lock()
+            .as_ref()
+            .is_some_and(|s| s.source == source && s.target.is_some())
+    }
+
+    /// Ends the drag of the `source` container, returning the transfer of
+    /// its item if it was dropped onto another container.
+    pub(crate) fn end(source: u64) -> Option<DragTransfer> {
+        let mut session = lock();
+
+        if session.as_ref()?.source != source {
+            return None;
+        }
+
+        let Session {
+            group,
+            index,
+            target,
+            ..
+        } = session.take()?;
+
+        let (_, target_group, target_index) = target?;
+
+        Some(DragTransfer {
+            source_group: group,
+            source_index: index,
+            target_group,
+            target_index,
+        })
+    }
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Session>> {
+    SESSION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Returns the slot among the items of a container, from `0` to their
+/// number, that the item at `index` is dropped in when moved to
+/// `target_index`.
+pub(crate) fn drop_slot(index: usize, target_index: usize) -> usize {
+    if target_index > index {
+        target_index + 1
+    } else {
+        target_index
+    }
+}
+
+/// Returns the new order of `count` items after moving the `moved` ones, in
+/// their current order, to `slot`.
+///
+/// The `i`-th element of the result is the current index of the item that
+/// ends up at position `i`.
+pub(crate) fn reorder(
+    count: usize,
+    moved: &[usize],
+    slot: usize,
+) -> Vec<usize> {
+    let (before, after): (Vec<_>, Vec<_>) = (0..count)
+        .filter(|i| !moved.contains(i))
+        .partition(|i| *i < slot);
+
+    before
+        .into_iter()
+        .chain(moved.iter().copied())
+        .chain(after)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::MutexGuard;
+
+    /// Gives the calling test exclusive use of the global drag session,
+    /// starting from no session.
+    ///
+    /// Every test that touches the session must hold it, since tests run in
+    /// parallel.
+    fn exclusive_session() -> MutexGuard<'static, ()> {
+        static EXCLUSIVE: Mutex<()> = Mutex::new(());
+
+        let guard = EXCLUSIVE
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        *lock() = None;
+
+        guard
+    }
+
+    #[test]
+    fn sessions_end_on_release() {
+        let _session = exclusive_session();
+        let source = Session::next_key();
+        let target = Session::next_key();
+
+        Session::start(source, "board", 2);
+
+        assert!(Session::hover(target, "board", Some(1)));
+        assert!(Session::is_transferring(source));
+
+        Session::release();
+
+        assert_eq!(
+            Session::end(source),
+            Some(DragTransfer {
+                source_group: "board".to_owned(),
+                source_index: 2,
+                target_group: "board".to_owned(),
+                target_index: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn sessions_go_stale_when_the_source_is_gone() {
+        let _session = exclusive_session();
+        let source = Session::next_key();
+        let target = Session::next_key();
+
+        // The source leaves the tree before the item is dropped
+        Session::start(source, "board", 0);
+        Session::release();
+
+        assert!(!Session::hover(target, "board", Some(1)));
+        assert_eq!(Session::end(source), None);
+    }
+
+    #[test]
+    fn drop_slot_skips_the_dragged_item() {
+        assert_eq!(drop_slot(2, 0), 0);
+        assert_eq!(drop_slot(2, 2), 2);
+        assert_eq!(drop_slot(0, 1), 2);
+        assert_eq!(drop_slot(0, 3), 4);
+    }
+
+    #[test]
+    fn reorder_moves_a_single_item() {
+        assert_eq!(reorder(4, &[0], drop_slot(0, 2)), vec![1, 2, 0, 3]);
+        assert_eq!(reorder(4, &[3], drop_slot(3, 0)), vec![3, 0, 1, 2]);
+        assert_eq!(reorder(4, &[1], drop_slot(1, 1)), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn reorder_keeps_the_order_of_moved_items() {
+        assert_eq!(reorder(5, &[3, 1], 0), vec![3, 1, 0, 2, 4]);
+        assert_eq!(reorder(5, &[0, 2], 5), vec![1, 3, 4, 0, 2]);
+        assert_eq!(reorder(5, &[1, 3], 3), vec![0, 2, 1, 3, 4]);
+    }
+}
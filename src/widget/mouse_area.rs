@@ -24,6 +24,7 @@
 //!         .into()
 //! }
 //! ```
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
@@ -35,6 +36,23 @@ use crate::core::{
     Vector, Widget,
 };
 
+use iced_widget::container;
+
+/// A closure deciding whether a press at a cursor position, with some
+/// modifiers held, emits a message.
+type PressFn<'a, Message> =
+    Box<dyn Fn(Point, keyboard::Modifiers) -> Option<Message> + 'a>;
+
+/// A closure receiving the name of a region and a cursor position.
+type RegionFn<'a, Message> = Box<dyn Fn(&str, Point) -> Message + 'a>;
+
+/// A closure styling the background of a hovered [`MouseArea`].
+type HoverStyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> container::Style + 'a>;
+
+/// A closure receiving a cursor position and a scroll delta.
+type ScrollFn<'a, Message> =
+    Box<dyn Fn(Point, mouse::ScrollDelta) -> Message + 'a>;
+
 /// Emit messages on mouse events.
 pub struct MouseArea<
     'a,
@@ -43,18 +61,24 @@ pub struct MouseArea<
     Renderer = crate::Renderer,
 > {
     content: Element<'a, Message, Theme, Renderer>,
-    on_press: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_press: Option<PressFn<'a, Message>>,
     on_release: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_double_click: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_right_press: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_right_release: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_middle_press: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_middle_release: Option<Box<dyn Fn(Point) -> Message + 'a>>,
-    on_scroll: Option<Box<dyn Fn(mouse::ScrollDelta) -> Message + 'a>>,
+    on_scroll: Option<ScrollFn<'a, Message>>,
     on_enter: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_drag: Option<Box<dyn Fn(Point, Vector) -> Message + 'a>>,
     on_exit: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    regions: Vec<(String, Rectangle)>,
+    on_region_enter: Option<RegionFn<'a, Message>>,
+    on_region_exit: Option<RegionFn<'a, Message>>,
     interaction: Option<mouse::Interaction>,
+    hover_style: Option<HoverStyleFn<'a, Theme>>,
+    intercept: bool,
 }
 
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
@@ -63,7 +87,47 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
     /// The closure receives the click position as a [`Point`].
     #[must_use]
     pub fn on_press(mut self, f: impl Fn(Point) -> Message + 'a) -> Self {
-        self.on_press = Some(Box::new(f));
+        self.on_press = Some(Box::new(move |position, _| Some(f(position))));
+        self
+    }
+
+    /// Sets a closure that decides, on a left button press, whether to emit
+    /// a message.
+    ///
+    /// The closure receives the click position as a [`Point`]:
+    ///
+    /// - Returning `Some` publishes the message and captures the event.
+    /// - Returning `None` publishes nothing and leaves the event uncaptured,
+    ///   so it passes through to the widgets behind the [`MouseArea`] and
+    ///   to its parents.
+    ///
+    /// This is useful to only handle presses in a sub-region of the
+    /// [`MouseArea`]. It replaces any handler set with
+    /// [`on_press`](Self::on_press).
+    #[must_use]
+    pub fn on_press_with(
+        mut self,
+        f: impl Fn(Point) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_press = Some(Box::new(move |position, _| f(position)));
+        self
+    }
+
+    /// Sets the message to emit on a left button press, from the click
+    /// position and the [`keyboard::Modifiers`] held at the time.
+    ///
+    /// This lets a press behave differently with modifiers, e.g. to extend a
+    /// selection with <kbd>Shift</kbd> held. It replaces any handler set with
+    /// [`on_press`](Self::on_press) or [`on_press_with`](Self::on_press_with),
+    /// and vice versa.
+    #[must_use]
+    pub fn on_press_with_modifiers(
+        mut self,
+        f: impl Fn(Point, keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_press = Some(Box::new(move |position, modifiers| {
+            Some(f(position, modifiers))
+        }));
         self
     }
 
@@ -75,7 +139,8 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         mut self,
         f: Option<impl Fn(Point) -> Message + 'a>,
     ) -> Self {
-        self.on_press = f.map(|f| Box::new(f) as _);
+        self.on_press =
+            f.map(|f| Box::new(move |position, _| Some(f(position))) as _);
         self
     }
 
@@ -92,6 +157,10 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
     ///
     /// The closure receives the click position as a [`Point`].
     ///
+    /// A press counts as the second click of a double click when it follows
+    /// the previous one closely in both time and position, as detected by
+    /// [`mouse::Click`]; a press far from the first one starts over.
+    ///
     /// If you use this with [`on_press`]/[`on_release`], those
     /// events will be emitted as normal.
     ///
@@ -112,6 +181,9 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
     /// Sets the message to emit on a right button press.
     ///
     /// The closure receives the click position as a [`Point`].
+    ///
+    /// The press is captured, so it does not reach the widgets behind the
+    /// [`MouseArea`]; without a handler, it passes through.
     #[must_use]
     pub fn on_right_press(mut self, f: impl Fn(Point) -> Message + 'a) -> Self {
         self.on_right_press = Some(Box::new(f));
@@ -133,6 +205,9 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
     /// Sets the message to emit on a middle button press.
     ///
     /// The closure receives the click position as a [`Point`].
+    ///
+    /// The press is captured, so it does not reach the widgets behind the
+    /// [`MouseArea`]; without a handler, it passes through.
     #[must_use]
     pub fn on_middle_press(
         mut self,
@@ -154,11 +229,15 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
-    /// Sets the message to emit when the scroll wheel is used.
+    /// Sets the message to emit when the scroll wheel is used over the area.
+    ///
+    /// The closure receives the cursor position as a [`Point`], along with
+    /// the [`mouse::ScrollDelta`]. The scroll event is only captured when
+    /// this handler is set.
     #[must_use]
     pub fn on_scroll(
         mut self,
-        on_scroll: impl Fn(mouse::ScrollDelta) -> Message + 'a,
+        on_scroll: impl Fn(Point, mouse::ScrollDelta) -> Message + 'a,
     ) -> Self {
         self.on_scroll = Some(Box::new(on_scroll));
         self
@@ -182,21 +261,127 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// Sets the message to emit when the mouse is dragged after a left press
+    /// in the area.
+    ///
+    /// The closure receives the current position as a [`Point`], along with
+    /// the [`Vector`] the cursor moved by since the previous message.
+    ///
+    /// Unlike [`on_move`], the drag keeps going when the cursor leaves the
+    /// area, until the button is released; positions outside of the area are
+    /// still relative to it. Cursor movements while not dragging only
+    /// trigger [`on_move`].
+    ///
+    /// [`on_move`]: Self::on_move
+    #[must_use]
+    pub fn on_drag(
+        mut self,
+        f: impl Fn(Point, Vector) -> Message + 'a,
+    ) -> Self {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
     /// Sets the message to emit when the mouse exits the area.
     ///
-    /// The closure receives the exit position as a [`Point`].
+    /// The closure receives the exit position as a [`Point`], relative to
+    /// the area and thus outside of its bounds. If the cursor left the window
+    /// altogether, the last known position is used instead.
     #[must_use]
     pub fn on_exit(mut self, f: impl Fn(Point) -> Message + 'a) -> Self {
         self.on_exit = Some(Box::new(f));
         self
     }
 
+    /// Sets the named sub-regions of the area, in coordinates relative to
+    /// its top-left corner.
+    ///
+    /// The cursor is in at most one region at a time: where regions overlap,
+    /// the last one in the list wins. Outside of every region, or outside of
+    /// the area, the cursor is in no region.
+    ///
+    /// Use [`on_region_enter`] and [`on_region_exit`] to be notified when the
+    /// cursor crosses them.
+    ///
+    /// [`on_region_enter`]: Self::on_region_enter
+    /// [`on_region_exit`]: Self::on_region_exit
+    #[must_use]
+    pub fn regions(mut self, regions: Vec<(String, Rectangle)>) -> Self {
+        self.regions = regions;
+        self
+    }
+
+    /// Sets the message to emit when the mouse enters one of the
+    /// [`regions`](Self::regions).
+    ///
+    /// The closure receives the name of the region and the entry position
+    /// as a [`Point`], relative to the area.
+    #[must_use]
+    pub fn on_region_enter(
+        mut self,
+        f: impl Fn(&str, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_region_enter = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message to emit when the mouse exits one of the
+    /// [`regions`](Self::regions).
+    ///
+    /// The closure receives the name of the region and the exit position
+    /// as a [`Point`], relative to the area. Moving straight from one region
+    /// to another exits the first before entering the second.
+    #[must_use]
+    pub fn on_region_exit(
+        mut self,
+        f: impl Fn(&str, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_region_exit = Some(Box::new(f));
+        self
+    }
+
     /// The [`mouse::Interaction`] to use when hovering the area.
     #[must_use]
     pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
         self.interaction = Some(interaction);
         self
     }
+
+    /// Sets whether the [`MouseArea`] handles events before its content.
+    ///
+    /// By default, the content gets every event first, and the handlers of
+    /// the [`MouseArea`] only run for the events it did not capture; e.g.
+    /// clicking a button inside of it does not trigger [`on_press`].
+    ///
+    /// When intercepting, the handlers run first instead. The events they
+    /// capture never reach the content: presses with a handler (unless
+    /// [`on_press_with`] returns `None`) and scrolling with [`on_scroll`].
+    /// Every other event, including releases and cursor movements, is still
+    /// passed on to the content.
+    ///
+    /// [`on_press`]: Self::on_press
+    /// [`on_press_with`]: Self::on_press_with
+    /// [`on_scroll`]: Self::on_scroll
+    #[must_use]
+    pub fn intercept(mut self, intercept: bool) -> Self {
+        self.intercept = intercept;
+        self
+    }
+
+    /// Sets the style of the [`MouseArea`] while the cursor is over it, to
+    /// highlight it without wrapping it in a `container`.
+    ///
+    /// The background and border of the style are drawn below the content,
+    /// and its text color, if any, is used as the default text color of the
+    /// content. Without it, the [`MouseArea`] draws nothing of its own.
+    #[must_use]
+    pub fn hover_style(
+        mut self,
+        style: impl Fn(&Theme) -> container::Style + 'a,
+    ) -> Self {
+        self.hover_style = Some(Box::new(style));
+        self
+    }
 }
 
 /// Local state of the [`MouseArea`].
@@ -205,6 +390,9 @@ struct State {
     is_hovered: bool,
     bounds: Rectangle,
     cursor_position: Option<Point>,
+    modifiers: keyboard::Modifiers,
+    drag_position: Option<Point>,
+    region: Option<String>,
     previous_click: Option<mouse::Click>,
 }
 
@@ -225,8 +413,14 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             on_scroll: None,
             on_enter: None,
             on_move: None,
+            on_drag: None,
             on_exit: None,
+            regions: Vec::new(),
+            on_region_enter: None,
+            on_region_exit: None,
             interaction: None,
+            hover_style: None,
+            intercept: false,
         }
     }
 }
@@ -295,6 +489,14 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        if self.intercept {
+            update(self, tree, event, layout, cursor, shell);
+
+            if shell.is_event_captured() {
+                return;
+            }
+        }
+
         self.content.as_widget_mut().update(
             &mut tree.children[0],
             event,
@@ -306,7 +508,7 @@ where
             viewport,
         );
 
-        if shell.is_event_captured() {
+        if self.intercept || shell.is_event_captured() {
             return;
         }
 
@@ -349,11 +551,25 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        let mut renderer_style = *renderer_style;
+
+        if let Some(hover_style) = &self.hover_style
+            && cursor.is_over(layout.bounds())
+        {
+            let style = hover_style(theme);
+
+            container::draw_background(renderer, &style, layout.bounds());
+
+            if let Some(text_color) = style.text_color {
+                renderer_style.text_color = text_color;
+            }
+        }
+
         self.content.as_widget().draw(
             &tree.children[0],
             renderer,
             theme,
-            renderer_style,
+            &renderer_style,
             layout,
             cursor,
             viewport,
@@ -404,17 +620,35 @@ fn update<Message, Theme, Renderer>(
 ) {
     let state: &mut State = tree.state.downcast_mut();
 
+    if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event
+    {
+        state.modifiers = *modifiers;
+    }
+
     let cursor_position = cursor.position();
     let bounds = layout.bounds();
 
     if state.cursor_position != cursor_position || state.bounds != bounds {
         let was_hovered = state.is_hovered;
+        let previous_position = state.cursor_position;
 
         state.is_hovered = cursor.is_over(layout.bounds());
         state.cursor_position = cursor_position;
         state.bounds = bounds;
 
-        if let Some(position) = cursor.position_in(layout.bounds()) {
+        if widget.hover_style.is_some() && state.is_hovered != was_hovered {
+            shell.request_redraw();
+        }
+
+        let relative = |position: Point| {
+            Point::ORIGIN + (position - Point::new(bounds.x, bounds.y))
+        };
+
+        // The cursor may leave the window without a last `CursorMoved` inside
+        // of the area, so exits fall back to the last known position.
+        let position = cursor_position.or(previous_position).map(relative);
+
+        if let Some(position) = position {
             match (
                 widget.on_enter.as_ref(),
                 widget.on_move.as_ref(),
@@ -432,6 +666,56 @@ fn update<Message, Theme, Renderer>(
                 _ => {}
             }
         }
+
+        let region = cursor.position_in(bounds).and_then(|position| {
+            widget
+                .regions
+                .iter()
+                .rev()
+                .find(|(_, region)| region.contains(position))
+                .map(|(name, _)| name)
+        });
+
+        if state.region.as_ref() != region
+            && let Some(position) = position
+        {
+            if let Some(previous) = state.region.take()
+                && let Some(on_region_exit) = widget.on_region_exit.as_ref()
+            {
+                shell.publish(on_region_exit(&previous, position));
+            }
+
+            if let Some(name) = region
+                && let Some(on_region_enter) = widget.on_region_enter.as_ref()
+            {
+                shell.publish(on_region_enter(name, position));
+            }
+
+            state.region = region.cloned();
+        }
+    }
+
+    if let Some(on_drag) = widget.on_drag.as_ref()
+        && let Some(last_position) = state.drag_position
+    {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let relative = Point::ORIGIN
+                    + (*position - Point::new(bounds.x, bounds.y));
+
+                shell.publish(on_drag(relative, *position - last_position));
+                shell.capture_event();
+
+                state.drag_position = Some(*position);
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.drag_position = None;
+            }
+            _ => {}
+        }
     }
 
     if !cursor.is_over(layout.bounds()) {
@@ -441,9 +725,16 @@ fn update<Message, Theme, Renderer>(
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if widget.on_drag.is_some() {
+                state.drag_position = cursor.position();
+                shell.capture_event();
+            }
+
             if let Some(on_press) = widget.on_press.as_ref() {
-                if let Some(position) = cursor.position_in(layout.bounds()) {
-                    shell.publish(on_press(position));
+                if let Some(position) = cursor.position_in(layout.bounds())
+                    && let Some(message) = on_press(position, state.modifiers)
+                {
+                    shell.publish(message);
                     shell.capture_event();
                 }
             }
@@ -507,11 +798,192 @@ fn update<Message, Theme, Renderer>(
             }
         }
         Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-            if let Some(on_scroll) = widget.on_scroll.as_ref() {
-                shell.publish(on_scroll(*delta));
+            if let Some(on_scroll) = widget.on_scroll.as_ref()
+                && let Some(position) = cursor.position_in(layout.bounds())
+            {
+                shell.publish(on_scroll(position, *delta));
                 shell.capture_event();
             }
         }
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::Harness;
+
+    use iced_widget::text;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Scrolled(Point, mouse::ScrollDelta),
+        Entered(Point),
+        Exited(Point),
+        Pressed(Point),
+        DoubleClicked(Point),
+        PressedWith(Point, keyboard::Modifiers),
+        RightPressed(Point),
+        MiddlePressed(Point),
+    }
+
+    fn mouse_area<'a>() -> MouseArea<'a, Message, crate::Theme, ()> {
+        MouseArea::new(text("Area").width(100).height(100))
+    }
+
+    fn press(
+        harness: &mut Harness<'_, Message>,
+        button: mouse::Button,
+    ) -> bool {
+        harness.event(Event::Mouse(mouse::Event::ButtonPressed(button)))
+    }
+
+    fn scroll(harness: &mut Harness<'_, Message>) -> bool {
+        harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+        }))
+    }
+
+    #[test]
+    fn scroll_reports_the_position_and_delta() {
+        let mouse_area = mouse_area().on_scroll(Message::Scrolled);
+        let mut harness = Harness::new(mouse_area, Size::new(200.0, 200.0));
+
+        harness.move_cursor(Point::new(30.0, 40.0));
+
+        assert!(scroll(&mut harness));
+        assert_eq!(
+            harness.messages(),
+            vec![Message::Scrolled(
+                Point::new(30.0, 40.0),
+                mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            )]
+        );
+    }
+
+    #[test]
+    fn scroll_passes_through_without_a_handler() {
+        let mut harness = Harness::new(mouse_area(), Size::new(200.0, 200.0));
+
+        harness.move_cursor(Point::new(30.0, 40.0));
+
+        assert!(!scroll(&mut harness));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn enter_and_exit_report_the_crossing_positions() {
+        let mouse_area = mouse_area()
+            .on_enter(Message::Entered)
+            .on_exit(Message::Exited);
+        let mut harness = Harness::new(mouse_area, Size::new(200.0, 200.0));
+
+        harness.move_cursor(Point::new(150.0, 50.0));
+        harness.move_cursor(Point::new(90.0, 50.0));
+        harness.move_cursor(Point::new(50.0, 50.0));
+        harness.move_cursor(Point::new(180.0, 20.0));
+
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::Entered(Point::new(90.0, 50.0)),
+                Message::Exited(Point::new(180.0, 20.0)),
+            ]
+        );
+
+        // Leaving the window uses the last known position
+        harness.move_cursor(Point::new(10.0, 10.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::CursorLeft));
+
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::Entered(Point::new(10.0, 10.0)),
+                Message::Exited(Point::new(10.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn close_clicks_are_a_double_click() {
+        let mouse_area = mouse_area()
+            .on_press(Message::Pressed)
+            .on_double_click(Message::DoubleClicked);
+        let mut harness = Harness::new(mouse_area, Size::new(200.0, 200.0));
+
+        harness.click(Point::new(20.0, 20.0));
+        harness.click(Point::new(21.0, 20.0));
+
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::Pressed(Point::new(20.0, 20.0)),
+                Message::Pressed(Point::new(21.0, 20.0)),
+                Message::DoubleClicked(Point::new(21.0, 20.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn distant_clicks_are_not_a_double_click() {
+        let mouse_area = mouse_area().on_double_click(Message::DoubleClicked);
+        let mut harness = Harness::new(mouse_area, Size::new(200.0, 200.0));
+
+        harness.click(Point::new(20.0, 20.0));
+        harness.click(Point::new(80.0, 80.0));
+
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn press_reports_the_held_modifiers() {
+        let mouse_area =
+            mouse_area().on_press_with_modifiers(Message::PressedWith);
+        let mut harness = Harness::new(mouse_area, Size::new(200.0, 200.0));
+
+        let _ = harness.set_modifiers(keyboard::Modifiers::CTRL);
+        harness.click(Point::new(20.0, 20.0));
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::PressedWith(
+                Point::new(20.0, 20.0),
+                keyboard::Modifiers::CTRL,
+            )]
+        );
+    }
+
+    #[test]
+    fn right_and_middle_presses_pass_through_without_a_handler() {
+        let mouse_area = mouse_area().on_press(Message::Pressed);
+        let mut harness = Harness::new(mouse_area, Size::new(200.0, 200.0));
+
+        harness.move_cursor(Point::new(30.0, 40.0));
+
+        assert!(!press(&mut harness, mouse::Button::Right));
+        assert!(!press(&mut harness, mouse::Button::Middle));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn right_and_middle_presses_only_reach_their_handlers() {
+        let mouse_area = mouse_area()
+            .on_press(Message::Pressed)
+            .on_right_press(Message::RightPressed)
+            .on_middle_press(Message::MiddlePressed);
+        let mut harness = Harness::new(mouse_area, Size::new(200.0, 200.0));
+
+        harness.move_cursor(Point::new(30.0, 40.0));
+
+        assert!(press(&mut harness, mouse::Button::Right));
+        assert!(press(&mut harness, mouse::Button::Middle));
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::RightPressed(Point::new(30.0, 40.0)),
+                Message::MiddlePressed(Point::new(30.0, 40.0)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1506 @@
+//! Change the internal state of widgets.
+//!
+//! This module complements [`iced_runtime::widget::operation`] with sweetened
+//! operations:
+//!
+//! - [`focus_next_where`] / [`focus_previous_where`] — Traverse only the
+//!   widgets matching a predicate
+//! - [`focus_next_no_wrap`] / [`focus_previous_no_wrap`] — Stop at the ends
+//!   instead of wrapping around
+//! - [`focus_first_within`] — Move focus into a container
+//! - [`focus_next_within`] / [`focus_previous_within`] — Keep focus inside a
+//!   container
+//! - [`focus_next_by_index`] / [`focus_previous_by_index`] — Follow explicit
+//!   tab indices
+//! - [`focused`] — Report the focused widget
+//! - [`blur_all`] — Clear the focus
+//! - [`set_enabled`] — Disable widgets from outside
+//! - [`press`] — Press a widget from outside
+//! - [`ui_snapshot`] — Capture the interactive state of the widget tree for
+//!   tests
+//!
+//! # Filtering by kind
+//!
+//! Widget [`Id`]s are the only thing an operation can tell focusable widgets
+//! apart by, and they are opaque. To traverse a subset of them (e.g. only
+//! text inputs, skipping buttons), give the widgets of each "kind" well-known
+//! ids and match on them with a predicate:
+//!
+//! ```no_run
+//! use sweeten::core::widget::Id;
+//! use sweeten::widget::operation;
+//!
+//! const NAME: Id = Id::new("field:name");
+//! const EMAIL: Id = Id::new("field:email");
+//!
+//! const FIELDS: &[Id] = &[NAME, EMAIL];
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     FieldFocused(Id),
+//! }
+//!
+//! let task = operation::focus_next_where(
+//!     |id| FIELDS.contains(id),
+//!     Message::FieldFocused,
+//! );
+//! ```
+//!
+//! For dynamic lists, build the ids from a prefix with [`Id::from`] and keep
+//! them around (or rebuild them) to check membership.
+//!
+//! # Trapping focus
+//!
+//! Focus traversal is not built into `iced`: the application moves focus
+//! when Tab is pressed, usually from a subscription. While a modal dialog is
+//! open, use [`focus_next_within`] and [`focus_previous_within`] instead of
+//! the global operations, so Tab cycles through the dialog only:
+//!
+//! ```no_run
+//! use iced::keyboard::{self, Key, key::Named};
+//! use sweeten::core::widget::Id;
+//! use sweeten::widget::operation;
+//!
+//! const DIALOG: Id = Id::new("dialog");
+//!
+//! #[derive(Clone)]
+//! enum Message {
+//!     FocusNext,
+//!     FocusPrevious,
+//! }
+//!
+//! fn subscription() -> iced::Subscription<Message> {
+//!     keyboard::listen().filter_map(|event| match event {
+//!         keyboard::Event::KeyPressed {
+//!             key: Key::Named(Named::Tab),
+//!             modifiers,
+//!             ..
+//!         } => Some(if modifiers.shift() {
+//!             Message::FocusPrevious
+//!         } else {
+//!             Message::FocusNext
+//!         }),
+//!         _ => None,
+//!     })
+//! }
+//!
+//! fn update(message: Message) -> iced::Task<Message> {
+//!     match message {
+//!         Message::FocusNext => operation::focus_next_within(DIALOG),
+//!         Message::FocusPrevious => operation::focus_previous_within(DIALOG),
+//!     }
+//! }
+//! ```
+use crate::core::Rectangle;
+use crate::core::widget::Id;
+#[cfg(feature = "button")]
+use crate::widget::button;
+
+use crate::core::widget::operation::{self, Focusable, Operation, Outcome};
+use std::any::Any;
+
+use iced_runtime::{Action, Task, task};
+
+/// Focuses the next focusable widget whose [`Id`] satisfies the given
+/// predicate, producing the message returned by `f` with the [`Id`] of the
+/// newly focused widget.
+///
+/// The search starts after the currently focused widget and wraps around
+/// within the matching widgets. If no focusable widget matches, nothing
+/// happens and no message is produced. Widgets without an [`Id`] never
+/// match.
+pub fn focus_next_where<T, F, P>(predicate: P, f: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce(Id) -> T + Send + 'static,
+    P: Fn(&Id) -> bool + Send + 'static,
+{
+    focus_where(predicate, next_match, f)
+}
+
+/// Focuses the previous focusable widget whose [`Id`] satisfies the given
+/// predicate, producing the message returned by `f` with the [`Id`] of the
+/// newly focused widget.
+///
+/// The search starts before the currently focused widget and wraps around
+/// within the matching widgets. If no focusable widget matches, nothing
+/// happens and no message is produced. Widgets without an [`Id`] never
+/// match.
+pub fn focus_previous_where<T, F, P>(predicate: P, f: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce(Id) -> T + Send + 'static,
+    P: Fn(&Id) -> bool + Send + 'static,
+{
+    focus_where(predicate, previous_match, f)
+}
+
+/// Focuses the matching candidate picked by `target`, producing the message
+/// returned by `f` with its [`Id`].
+fn focus_where<T, F, P>(
+    predicate: P,
+    target: fn(&[Candidate]) -> Option<usize>,
+    f: F,
+) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce(Id) -> T + Send + 'static,
+    P: Fn(&Id) -> bool + Send + 'static,
+{
+    struct FocusMatch {
+        focus: FocusIndex,
+        id: Option<Id>,
+    }
+
+    impl Operation<Option<Id>> for FocusMatch {
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            Operation::<()>::focusable(&mut self.focus, id, bounds, state);
+        }
+
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<Option<Id>>),
+        ) {
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<Option<Id>> {
+            Outcome::Some(self.id.clone())
+        }
+    }
+
+    let mut f = Some(f);
+
+    // Finding and focusing the target in a single operation keeps other
+    // focus changes from slipping in between.
+    let operation = operation::then(
+        operation::map(candidates(predicate), move |candidates| {
+            let target = target(&candidates);
+
+            (target, target.and_then(|i| candidates[i].id.clone()))
+        }),
+        |(target, id)| FocusMatch {
+            focus: FocusIndex { target, current: 0 },
+            id,
+        },
+    );
+
+    task::widget(operation).then(move |id| match (id, f.take()) {
+        (Some(id), Some(f)) => Task::done(f(id)),
+        _ => Task::none(),
+    })
+}
+
+/// Focuses the next focusable widget, without wrapping around.
+///
+/// If the focused widget is the last focusable widget in tree order, focus
+/// stays where it is and the [`Task`] produces the message returned by
+/// `on_boundary` instead; e.g. to submit a form when pressing Tab on its last
+/// field. The same happens if there are no focusable widgets at all.
+///
+/// The focused widget is the one [`find_focused`] would report, except that
+/// widgets without an [`Id`] are taken into account too. If no widget is
+/// focused, the first focusable widget is focused.
+///
+/// [`find_focused`]: operation::focusable::find_focused
+pub fn focus_next_no_wrap<T, F>(on_boundary: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    focus_no_wrap(
+        |candidates| {
+            let next = candidates
+                .iter()
+                .position(|candidate| candidate.is_focused)
+                .map_or(0, |focused| focused + 1);
+
+            (next < candidates.len()).then_some(next)
+        },
+        on_boundary,
+    )
+}
+
+/// Focuses the previous focusable widget, without wrapping around.
+///
+/// If the focused widget is the first focusable widget in tree order, focus
+/// stays where it is and the [`Task`] produces the message returned by
+/// `on_boundary` instead. The same happens if there are no focusable widgets
+/// at all.
+///
+/// The focused widget is the one [`find_focused`] would report, except that
+/// widgets without an [`Id`] are taken into account too. If no widget is
+/// focused, the last focusable widget is focused.
+///
+/// [`find_focused`]: operation::focusable::find_focused
+pub fn focus_previous_no_wrap<T, F>(on_boundary: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    focus_no_wrap(
+        |candidates| match candidates
+            .iter()
+            .position(|candidate| candidate.is_focused)
+        {
+            Some(focused) => focused.checked_sub(1),
+            None => candidates.len().checked_sub(1),
+        },
+        on_boundary,
+    )
+}
+
+/// Focuses the first focusable widget inside the container with the given
+/// [`Id`], producing the message returned by `f` with the [`Id`] of the
+/// focused widget.
+///
+/// Only descendants of the container are considered, in tree order; every
+/// other widget is unfocused. This is useful to move focus into a dialog
+/// when it opens. If the container has no focusable widgets, or does not
+/// exist, nothing happens. Widgets without an [`Id`] are skipped.
+///
+/// The container is found through [`Operation::container`], so it must be
+/// a widget that reports its [`Id`] there, like a `container`.
+pub fn focus_first_within<T, F>(container: impl Into<Id>, f: F) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce(Id) -> T + Send + 'static,
+{
+    struct FirstFocusable {
+        id: Option<Id>,
+    }
+
+    impl Operation<Option<Id>> for FirstFocusable {
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            _state: &mut dyn Focusable,
+        ) {
+            if self.id.is_none() {
+                self.id = id.cloned();
+            }
+        }
+
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<Option<Id>>),
+        ) {
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<Option<Id>> {
+            Outcome::Some(self.id.clone())
+        }
+    }
+
+    let mut f = Some(f);
+
+    task::widget(operation::scope(
+        container.into(),
+        FirstFocusable { id: None },
+    ))
+    .then(move |id| match (id, f.take()) {
+        (Some(id), Some(f)) => task::effect::<T>(Action::widget(
+            operation::focusable::focus(id.clone()),
+        ))
+        .chain(Task::done(f(id))),
+        _ => Task::none(),
+    })
+}
+
+/// Focuses the next focusable widget inside the container with the given
+/// [`Id`], wrapping around from the last one to the first one.
+///
+/// Widgets outside of the container are never focused, which traps focus
+/// inside it, e.g. in a modal dialog. If the focus is outside, the first
+/// widget inside after it in tree order is focused. If the container has
+/// no focusable widgets, or does not exist, nothing happens.
+///
+/// Like with [`focus_first_within`], the container must report its [`Id`]
+/// through [`Operation::container`]. Widgets without an [`Id`] are
+/// traversed too.
+pub fn focus_next_within<T>(container: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::then(
+        candidates_within(container.into()),
+        |candidates| FocusIndex {
+            target: next_match(&candidates),
+            current: 0,
+        },
+    )))
+}
+
+/// Focuses the previous focusable widget inside the container with the
+/// given [`Id`], wrapping around from the first one to the last one.
+///
+/// See [`focus_next_within`].
+pub fn focus_previous_within<T>(container: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::then(
+        candidates_within(container.into()),
+        |candidates| FocusIndex {
+            target: previous_match(&candidates),
+            current: 0,
+        },
+    )))
+}
+
+/// Produces the [`Id`] of the focused widget, without changing focus.
+///
+/// Unlike running [`find_focused`] directly, which never completes when
+/// nothing is focused, this always produces a value: `None` if no widget
+/// with an [`Id`] is focused. Chaining [`find_focused`] after a focus
+/// change, like [`text_input::focus_next`] does, works because some widget
+/// was just focused; use this to learn about the focus at any other time,
+/// e.g. to show it in a status bar.
+///
+/// [`find_focused`]: operation::focusable::find_focused
+/// [`text_input::focus_next`]: crate::widget::text_input::focus_next
+pub fn focused() -> Task<Option<Id>> {
+    struct Focused {
+        id: Option<Id>,
+    }
+
+    impl Operation<Option<Id>> for Focused {
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            if self.id.is_none() && state.is_focused() {
+                self.id = id.cloned();
+            }
+        }
+
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<Option<Id>>),
+        ) {
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<Option<Id>> {
+            Outcome::Some(self.id.clone())
+        }
+    }
+
+    task::widget(Focused { id: None })
+}
+
+/// Unfocuses every focusable widget, e.g. to drop the focus of the widgets
+/// behind a modal dialog when it opens.
+///
+/// Operations cannot produce messages, so the sweetened [`Button`] and
+/// [`TextInput`] notice the change when they handle their next event, and
+/// produce their `on_blur` message then; usually on the redraw that follows.
+///
+/// [`Button`]: crate::widget::Button
+/// [`TextInput`]: crate::widget::TextInput
+pub fn blur_all<T>() -> Task<T> {
+    struct BlurAll;
+
+    impl<T> Operation<T> for BlurAll {
+        fn focusable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            state.unfocus();
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+    }
+
+    task::effect(Action::widget(BlurAll))
+}
+
+/// Focuses the next focusable widget in ascending [`TabIndex`] order,
+/// wrapping around from the last one to the first one.
+///
+/// Widgets with the same index, including the ones without any (which have
+/// the default index of 0), keep their tree order. Widgets with a negative
+/// index are skipped, but they can still be focused by clicking them or with
+/// [`focus`](operation::focusable::focus). If the focused widget is one of
+/// them, or no widget is focused, the first widget in order is focused.
+///
+/// Currently, [`Button`] and [`TextInput`] report their index, set with
+/// [`Button::tab_index`] and [`TextInput::tab_index`].
+///
+/// [`Button`]: crate::widget::Button
+/// [`TextInput`]: crate::widget::TextInput
+/// [`Button::tab_index`]: crate::widget::Button::tab_index
+/// [`TextInput::tab_index`]: crate::widget::TextInput::tab_index
+pub fn focus_next_by_index<T>() -> Task<T> {
+    task::effect(Action::widget(operation::then(tab_order(), |order| {
+        let next = order
+            .iter()
+            .position(|&(_, is_focused)| is_focused)
+            .map_or(0, |focused| (focused + 1) % order.len().max(1));
+
+        FocusIndex {
+            target: order.get(next).map(|&(index, _)| index),
+            current: 0,
+        }
+    })))
+}
+
+/// Focuses the previous focusable widget in descending [`TabIndex`] order,
+/// wrapping around from the first one to the last one.
+///
+/// See [`focus_next_by_index`].
+pub fn focus_previous_by_index<T>() -> Task<T> {
+    task::effect(Action::widget(operation::then(tab_order(), |order| {
+        let previous = order
+            .iter()
+            .position(|&(_, is_focused)| is_focused)
+            .unwrap_or(0)
+            .checked_sub(1)
+            .unwrap_or(order.len().saturating_sub(1));
+
+        FocusIndex {
+            target: order.get(previous).map(|&(index, _)| index),
+            current: 0,
+        }
+    })))
+}
+
+/// Focuses the focusable widget picked by `target`, or produces the message
+/// of `on_boundary` if there is none.
+fn focus_no_wrap<T, F>(
+    target: fn(&[Candidate]) -> Option<usize>,
+    on_boundary: F,
+) -> Task<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let mut on_boundary = Some(on_boundary);
+
+    task::widget(candidates(|_| true)).then(move |candidates| {
+        match target(&candidates) {
+            Some(target) => task::effect(Action::widget(FocusIndex {
+                target: Some(target),
+                current: 0,
+            })),
+            None => on_boundary.take().map_or_else(Task::none, |on_boundary| {
+                Task::done(on_boundary())
+            }),
+        }
+    })
+}
+
+/// Enables or disables the widget with the given [`Id`].
+///
+/// Disabling a widget makes it behave as if disabled through its builder
+/// (e.g. a [`Button`] without [`on_press`]); if it is focused, it is blurred.
+/// Enabling it only undoes a previous `set_enabled(id, false)`: the builder
+/// always wins, so a widget disabled through its builder stays disabled.
+///
+/// The change lives in the state of the widget, and it lasts until it is
+/// undone or the widget is removed from the widget tree.
+///
+/// Currently, only [`Button`] honors this operation. Other widgets can opt in
+/// by keeping an [`EnabledState`] in their tree state, passing it to
+/// [`Operation::custom`] in `operate`, and checking it along their builder
+/// flags.
+///
+/// [`Button`]: crate::widget::Button
+/// [`on_press`]: crate::widget::Button::on_press
+pub fn set_enabled<T>(id: impl Into<Id>, is_enabled: bool) -> Task<T> {
+    struct SetEnabled {
+        id: Id,
+        is_enabled: bool,
+    }
+
+    impl<T> Operation<T> for SetEnabled {
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.id)
+                && let Some(state) = state.downcast_mut::<EnabledState>()
+            {
+                state.is_enabled = self.is_enabled;
+            }
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+    }
+
+    task::effect(Action::widget(SetEnabled {
+        id: id.into(),
+        is_enabled,
+    }))
+}
+
+/// Whether a widget has been enabled or disabled with [`set_enabled`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnabledState {
+    is_enabled: bool,
+}
+
+impl EnabledState {
+    /// Returns whether the widget is enabled, as far as [`set_enabled`] is
+    /// concerned.
+    pub fn is_enabled(self) -> bool {
+        self.is_enabled
+    }
+}
+
+impl Default for EnabledState {
+    fn default() -> Self {
+        Self { is_enabled: true }
+    }
+}
+
+/// Presses the widget with the given [`Id`], as if clicked.
+///
+/// This is meant for scripted tours and tests, to trigger a press without
+/// simulating mouse events. A disabled widget ignores it.
+///
+/// Operations cannot produce messages, so the press is only recorded in the
+/// state of the widget, which produces its message when it handles its next
+/// event; usually the redraw that follows, one frame later.
+///
+/// Currently, only [`Button`] honors this operation. Other widgets can opt in
+/// by keeping a [`PendingPress`] in their tree state, passing it to
+/// [`Operation::custom`] in `operate`, and taking it in `update`.
+///
+/// [`Button`]: crate::widget::Button
+pub fn press<T>(id: impl Into<Id>) -> Task<T> {
+    struct Press {
+        id: Id,
+    }
+
+    impl<T> Operation<T> for Press {
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if id == Some(&self.id)
+                && let Some(state) = state.downcast_mut::<PendingPress>()
+            {
+                state.is_pending = true;
+            }
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+    }
+
+    task::effect(Action::widget(Press { id: id.into() }))
+}
+
+/// Whether a widget has been pressed with [`press`] and has yet to produce
+/// its message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PendingPress {
+    is_pending: bool,
+}
+
+impl PendingPress {
+    /// Returns whether a press is pending, clearing it.
+    pub fn take(&mut self) -> bool {
+        std::mem::take(&mut self.is_pending)
+    }
+}
+
+/// The position of a focusable widget in the order of
+/// [`focus_next_by_index`], from lowest to highest.
+///
+/// Widgets report it by passing it to [`Operation::custom`] in `operate`,
+/// right before [`Operation::focusable`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TabIndex(pub i32);
+
+/// Captures the interactive state of every widget with an [`Id`].
+///
+/// The [`UiSnapshot`] lists the widgets in tree order, with their focus and
+/// any [`WidgetState`] they report. This is meant for UI tests, to assert on
+/// the state of the interface as a whole after simulating some input:
+///
+/// ```no_run
+/// use sweeten::widget::operation::{self, UiSnapshot};
+///
+/// # #[derive(Clone)] enum Message { Snapshot(UiSnapshot) }
+/// let task = operation::ui_snapshot().map(Message::Snapshot);
+/// ```
+///
+/// Widgets without an [`Id`] cannot be told apart, so they are left out.
+pub fn ui_snapshot() -> Task<UiSnapshot> {
+    struct Snapshot {
+        snapshot: UiSnapshot,
+    }
+
+    impl Snapshot {
+        fn widget(&mut self, id: &Id) -> &mut WidgetSnapshot {
+            let widgets = &mut self.snapshot.widgets;
+
+            match widgets.iter().position(|widget| widget.id == *id) {
+                Some(index) => &mut widgets[index],
+                None => {
+                    widgets.push(WidgetSnapshot {
+                        id: id.clone(),
+                        is_focused: None,
+                        state: None,
+                    });
+
+                    widgets.last_mut().expect("widget was just pushed")
+                }
+            }
+        }
+    }
+
+    impl Operation<UiSnapshot> for Snapshot {
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            if let Some(id) = id {
+                self.widget(id).is_focused = Some(state.is_focused());
+            }
+        }
+
+        // Without the widgets that report it, `WidgetState` is uninhabited
+        #[cfg(any(
+            feature = "button",
+            feature = "pick_list",
+            feature = "text_input"
+        ))]
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if let Some(id) = id
+                && let Some(state) = state.downcast_ref::<WidgetState>()
+            {
+                self.widget(id).state = Some(state.clone());
+            }
+        }
+
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<UiSnapshot>),
+        ) {
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<UiSnapshot> {
+            Outcome::Some(self.snapshot.clone())
+        }
+    }
+
+    task::widget(Snapshot {
+        snapshot: UiSnapshot::default(),
+    })
+}
+
+/// The interactive state of a widget tree, captured by [`ui_snapshot`].
+///
+/// # Stability
+/// A [`UiSnapshot`] is plain data, meant to be compared within a test run.
+/// New kinds of [`WidgetState`] may be reported as more widgets opt in, so
+/// match on them with a wildcard arm; and the [`Debug`] output is not stable
+/// across releases, so avoid storing it as a golden file.
+///
+/// With the `serde` feature, a [`UiSnapshot`] can be serialized, e.g. to
+/// attach it to a failing test report. Widget [`Id`]s are opaque, so they
+/// are serialized as their [`Debug`] representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UiSnapshot {
+    widgets: Vec<WidgetSnapshot>,
+}
+
+impl UiSnapshot {
+    /// Returns the captured widgets, in tree order.
+    pub fn widgets(&self) -> &[WidgetSnapshot] {
+        &self.widgets
+    }
+
+    /// Returns the captured widget with the given [`Id`], if any.
+    pub fn get(&self, id: &Id) -> Option<&WidgetSnapshot> {
+        self.widgets.iter().find(|widget| widget.id == *id)
+    }
+
+    /// Returns the [`Id`] of the focused widget, if any.
+    pub fn focused(&self) -> Option<&Id> {
+        self.widgets
+            .iter()
+            .find(|widget| widget.is_focused == Some(true))
+            .map(|widget| &widget.id)
+    }
+}
+
+/// The interactive state of a single widget in a [`UiSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WidgetSnapshot {
+    /// The [`Id`] of the widget.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_id"))]
+    pub id: Id,
+    /// Whether the widget is focused, if it is focusable.
+    pub is_focused: Option<bool>,
+    /// The state reported by the widget, if it reports any.
+    pub state: Option<WidgetState>,
+}
+
+/// The state a widget reports to [`ui_snapshot`].
+///
+/// Widgets report it by passing it to [`Operation::custom`] in `operate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum WidgetState {
+    /// A [`Button`](crate::widget::Button).
+    #[cfg(feature = "button")]
+    Button {
+        /// The current status of the button.
+        status: button::Status,
+        /// Whether the panel or menu controlled by the button is expanded,
+        /// if it controls any.
+        expanded: Option<bool>,
+    },
+    /// A [`PickList`](crate::widget::PickList).
+    #[cfg(feature = "pick_list")]
+    PickList {
+        /// Whether the menu of the pick list is open.
+        is_open: bool,
+    },
+    /// A [`TextInput`](crate::widget::TextInput).
+    #[cfg(feature = "text_input")]
+    TextInput {
+        /// The position of the caret, in graphemes.
+        cursor: usize,
+        /// The selected range of graphemes, if any.
+        selection: Option<(usize, usize)>,
+    },
+}
+
+#[cfg(feature = "serde")]
+fn serialize_id<S>(id: &Id, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(&format_args!("{id:?}"))
+}
+
+/// A focusable widget found while traversing the widget tree.
+#[derive(Debug, Clone)]
+struct Candidate {
+    id: Option<Id>,
+    is_match: bool,
+    is_focused: bool,
+}
+
+/// Produces an [`Operation`] that lists every focusable widget in tree order,
+/// evaluating the predicate for each of them.
+fn candidates<P>(predicate: P) -> impl Operation<Vec<Candidate>>
+where
+    P: Fn(&Id) -> bool + Send + 'static,
+{
+    struct Candidates<P> {
+        predicate: P,
+        candidates: Vec<Candidate>,
+    }
+
+    impl<P> Operation<Vec<Candidate>> for Candidates<P>
+    where
+        P: Fn(&Id) -> bool + Send,
+    {
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            self.candidates.push(Candidate {
+                id: id.cloned(),
+                is_match: id.is_some_and(&self.predicate),
+                is_focused: state.is_focused(),
+            });
+        }
+
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<Vec<Candidate>>),
+        ) {
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<Vec<Candidate>> {
+            Outcome::Some(self.candidates.clone())
+        }
+    }
+
+    Candidates {
+        predicate,
+        candidates: Vec::new(),
+    }
+}
+
+/// Produces an [`Operation`] that lists every focusable widget in tree order,
+/// matching the ones inside the container with the given [`Id`].
+fn candidates_within(container: Id) -> impl Operation<Vec<Candidate>> {
+    struct CandidatesWithin {
+        container: Id,
+        current: Option<Id>,
+        is_inside: bool,
+        candidates: Vec<Candidate>,
+    }
+
+    impl Operation<Vec<Candidate>> for CandidatesWithin {
+        fn container(&mut self, id: Option<&Id>, _bounds: Rectangle) {
+            self.current = id.cloned();
+        }
+
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            self.candidates.push(Candidate {
+                id: id.cloned(),
+                is_match: self.is_inside,
+                is_focused: state.is_focused(),
+            });
+        }
+
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<Vec<Candidate>>),
+        ) {
+            let was_inside = self.is_inside;
+
+            self.is_inside |=
+                self.current.take().as_ref() == Some(&self.container);
+
+            operate(self);
+
+            self.is_inside = was_inside;
+        }
+
+        fn finish(&self) -> Outcome<Vec<Candidate>> {
+            Outcome::Some(self.candidates.clone())
+        }
+    }
+
+    CandidatesWithin {
+        container,
+        current: None,
+        is_inside: false,
+        candidates: Vec::new(),
+    }
+}
+
+/// Produces an [`Operation`] that lists the focusable widgets with a
+/// non-negative [`TabIndex`] in ascending order, with their position in tree
+/// order and whether they are focused.
+fn tab_order() -> impl Operation<Vec<(usize, bool)>> {
+    struct TabOrder {
+        pending: Option<TabIndex>,
+        widgets: Vec<(TabIndex, bool)>,
+    }
+
+    impl Operation<Vec<(usize, bool)>> for TabOrder {
+        fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle) {
+            self.pending = None;
+        }
+
+        fn custom(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            if let Some(index) = state.downcast_ref::<TabIndex>() {
+                self.pending = Some(*index);
+            }
+        }
+
+        fn focusable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            self.widgets.push((
+                self.pending.take().unwrap_or_default(),
+                state.is_focused(),
+            ));
+        }
+
+        fn traverse(
+            &mut self,
+            operate: &mut dyn FnMut(&mut dyn Operation<Vec<(usize, bool)>>),
+        ) {
+            self.pending = None;
+
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<Vec<(usize, bool)>> {
+            let mut order: Vec<_> = self
+                .widgets
+                .iter()
+                .enumerate()
+                .filter(|(_, (index, _))| index.0 >= 0)
+                .map(|(i, &(index, is_focused))| (index, i, is_focused))
+                .collect();
+
+            // Sorting is stable, so equal indices keep their tree order
+            order.sort_by_key(|&(index, _, _)| index);
+
+            Outcome::Some(
+                order
+                    .into_iter()
+                    .map(|(_, i, is_focused)| (i, is_focused))
+                    .collect(),
+            )
+        }
+    }
+
+    TabOrder {
+        pending: None,
+        widgets: Vec::new(),
+    }
+}
+
+/// Returns the index of the first matching candidate after the focused one,
+/// wrapping around.
+fn next_match(candidates: &[Candidate]) -> Option<usize> {
+    let total = candidates.len();
+    let start = candidates
+        .iter()
+        .position(|candidate| candidate.is_focused)
+        .map_or(0, |focused| focused + 1);
+
+    (0..total)
+        .map(|offset| (start + offset) % total)
+        .find(|&i| candidates[i].is_match)
+}
+
+/// Returns the index of the first matching candidate before the focused one,
+/// wrapping around.
+fn previous_match(candidates: &[Candidate]) -> Option<usize> {
+    let total = candidates.len();
+    let start = candidates
+        .iter()
+        .position(|candidate| candidate.is_focused)
+        .unwrap_or(total);
+
+    (1..=total)
+        .map(|offset| (start + total - offset) % total)
+        .find(|&i| candidates[i].is_match)
+}
+
+/// An [`Operation`] that focuses the focusable widget at the given position in
+/// tree order, unfocusing every other one.
+///
+/// Does nothing if there is no target.
+struct FocusIndex {
+    target: Option<usize>,
+    current: usize,
+}
+
+impl<T> Operation<T> for FocusIndex {
+    fn focusable(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        state: &mut dyn Focusable,
+    ) {
+        let Some(target) = self.target else {
+            return;
+        };
+
+        if self.current == target {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+}
+
+/// An [`Operation`] that finds out whether any focusable widget it traverses
+/// is focused.
+#[cfg(any(feature = "column", feature = "row"))]
+#[derive(Debug, Default)]
+pub(crate) struct ContainsFocus {
+    pub(crate) is_focused: bool,
+}
+
+#[cfg(any(feature = "column", feature = "row"))]
+impl<T> Operation<T> for ContainsFocus {
+    fn focusable(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        state: &mut dyn Focusable,
+    ) {
+        self.is_focused |= state.is_focused();
+    }
+
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+}
+
+#[cfg(all(test, feature = "button"))]
+mod tests {
+    use super::*;
+    use crate::core::{Element, Size};
+    use crate::widget::{Button, button};
+
+    use iced_widget::{column, container, text};
+
+    use std::time::Instant;
+
+    type Harness = crate::test::Harness<'static, Id>;
+
+    fn button(id: &'static str) -> Element<'static, Id, crate::Theme, ()> {
+        Button::new(text(id))
+            .id(Id::new(id))
+            .on_press(Id::new(id))
+            .into()
+    }
+
+    fn harness(ids: &[&'static str]) -> Harness {
+        Harness::new(
+            column(ids.iter().copied().map(button)),
+            Size::new(200.0, 400.0),
+        )
+    }
+
+    fn focused(harness: &mut Harness) -> Option<Id> {
+        harness.run(super::focused()).pop().flatten()
+    }
+
+    fn status(harness: &mut Harness, id: &'static str) -> button::Status {
+        let snapshot = harness.run(ui_snapshot()).pop().unwrap();
+
+        match snapshot.get(&Id::new(id)).unwrap().state {
+            Some(WidgetState::Button { status, .. }) => status,
+            _ => panic!("{id} is not a button"),
+        }
+    }
+
+    fn focus_order(
+        harness: &mut Harness,
+        task: impl Fn() -> Task<()>,
+        steps: usize,
+    ) -> Vec<Option<Id>> {
+        (0..steps)
+            .map(|_| {
+                let _ = harness.run(task());
+
+                focused(harness)
+            })
+            .collect()
+    }
+
+    fn dialog() -> Harness {
+        Harness::new(
+            column![
+                button("before"),
+                container(column![button("a"), button("b")])
+                    .id(Id::new("dialog")),
+                button("after"),
+            ],
+            Size::new(200.0, 400.0),
+        )
+    }
+
+    fn indexed(ids: &[(&'static str, i32)]) -> Harness {
+        Harness::new(
+            column(ids.iter().map(|&(id, tab_index)| {
+                Button::new(text(id))
+                    .id(Id::new(id))
+                    .on_press(Id::new(id))
+                    .tab_index(tab_index)
+                    .into()
+            })),
+            Size::new(200.0, 400.0),
+        )
+    }
+
+    #[test]
+    fn focus_next_where_skips_widgets_not_matching() {
+        let mut harness = harness(&["a", "skip", "b"]);
+        let is_match = |id: &Id| *id != Id::new("skip");
+
+        assert_eq!(
+            harness.run(focus_next_where(is_match, |id| id)),
+            vec![Id::new("a")]
+        );
+        assert_eq!(
+            harness.run(focus_next_where(is_match, |id| id)),
+            vec![Id::new("b")]
+        );
+        assert_eq!(
+            harness.run(focus_next_where(is_match, |id| id)),
+            vec![Id::new("a")]
+        );
+        assert_eq!(focused(&mut harness), Some(Id::new("a")));
+    }
+
+    #[test]
+    fn focus_previous_where_wraps_around() {
+        let mut harness = harness(&["a", "b", "skip"]);
+        let is_match = |id: &Id| *id != Id::new("skip");
+
+        assert_eq!(
+            harness.run(focus_previous_where(is_match, |id| id)),
+            vec![Id::new("b")]
+        );
+        assert_eq!(
+            harness.run(focus_previous_where(is_match, |id| id)),
+            vec![Id::new("a")]
+        );
+        assert_eq!(
+            harness.run(focus_previous_where(is_match, |id| id)),
+            vec![Id::new("b")]
+        );
+    }
+
+    #[test]
+    fn focus_where_starts_from_a_focused_widget_not_matching() {
+        let mut harness = harness(&["a", "skip", "b"]);
+        let is_match = |id: &Id| *id != Id::new("skip");
+
+        harness.operate(&mut operation::focusable::focus(Id::new("skip")));
+
+        assert_eq!(
+            harness.run(focus_next_where(is_match, |id| id)),
+            vec![Id::new("b")]
+        );
+        assert_eq!(focused(&mut harness), Some(Id::new("b")));
+
+        harness.operate(&mut operation::focusable::focus(Id::new("skip")));
+
+        assert_eq!(
+            harness.run(focus_previous_where(is_match, |id| id)),
+            vec![Id::new("a")]
+        );
+        assert_eq!(focused(&mut harness), Some(Id::new("a")));
+    }
+
+    #[test]
+    fn focus_next_where_does_nothing_without_matches() {
+        let mut harness = harness(&["a", "b"]);
+
+        assert!(harness.run(focus_next_where(|_| false, |id| id)).is_empty());
+        assert_eq!(focused(&mut harness), None);
+    }
+
+    #[test]
+    fn focused_returns_the_focused_widget() {
+        let mut harness = harness(&["a", "b", "c"]);
+
+        assert_eq!(focused(&mut harness), None);
+
+        harness.operate(&mut operation::focusable::focus(Id::new("b")));
+
+        assert_eq!(focused(&mut harness), Some(Id::new("b")));
+        assert_eq!(focused(&mut harness), Some(Id::new("b")));
+    }
+
+    #[test]
+    fn blur_all_unfocuses_every_widget() {
+        struct FocusAll;
+
+        impl Operation for FocusAll {
+            fn focusable(
+                &mut self,
+                _id: Option<&Id>,
+                _bounds: Rectangle,
+                state: &mut dyn Focusable,
+            ) {
+                state.focus();
+            }
+
+            fn traverse(
+                &mut self,
+                operate: &mut dyn FnMut(&mut dyn Operation),
+            ) {
+                operate(self);
+            }
+        }
+
+        let mut harness = harness(&["a", "b"]);
+        harness.operate(&mut FocusAll);
+
+        let is_focused = |harness: &mut Harness| {
+            let snapshot = harness.run(ui_snapshot()).pop().unwrap();
+
+            ["a", "b"].map(|id| snapshot.get(&Id::new(id)).unwrap().is_focused)
+        };
+
+        assert_eq!(is_focused(&mut harness), [Some(true), Some(true)]);
+
+        let _ = harness.run::<()>(blur_all());
+
+        assert_eq!(is_focused(&mut harness), [Some(false), Some(false)]);
+        assert_eq!(focused(&mut harness), None);
+    }
+
+    #[test]
+    fn focus_next_skips_disabled_buttons() {
+        use iced_runtime::widget::operation::focus_next;
+
+        let mut harness = Harness::new(
+            column![
+                button("a"),
+                Button::new(text("disabled")).id(Id::new("disabled")),
+                button("b"),
+            ],
+            Size::new(200.0, 400.0),
+        );
+
+        let _ = harness.run::<()>(focus_next());
+        assert_eq!(focused(&mut harness), Some(Id::new("a")));
+
+        let _ = harness.run::<()>(focus_next());
+        assert_eq!(focused(&mut harness), Some(Id::new("b")));
+    }
+
+    #[test]
+    fn focus_next_by_index_follows_ascending_indices() {
+        let mut harness = indexed(&[("a", 2), ("b", 0), ("c", 1)]);
+
+        assert_eq!(
+            focus_order(&mut harness, focus_next_by_index, 4),
+            ["b", "c", "a", "b"].map(|id| Some(Id::new(id)))
+        );
+    }
+
+    #[test]
+    fn focus_previous_by_index_follows_descending_indices() {
+        let mut harness = indexed(&[("a", 2), ("b", 0), ("c", 1)]);
+
+        assert_eq!(
+            focus_order(&mut harness, focus_previous_by_index, 4),
+            ["a", "c", "b", "a"].map(|id| Some(Id::new(id)))
+        );
+    }
+
+    #[test]
+    fn equal_tab_indices_keep_the_tree_order() {
+        let mut harness = indexed(&[("a", 1), ("b", 0), ("c", 1), ("d", 0)]);
+
+        assert_eq!(
+            focus_order(&mut harness, focus_next_by_index, 4),
+            ["b", "d", "a", "c"].map(|id| Some(Id::new(id)))
+        );
+    }
+
+    #[test]
+    fn negative_tab_indices_are_skipped_but_still_focusable() {
+        let mut harness = indexed(&[("a", 0), ("skip", -1), ("b", 0)]);
+
+        assert_eq!(
+            focus_order(&mut harness, focus_next_by_index, 3),
+            ["a", "b", "a"].map(|id| Some(Id::new(id)))
+        );
+
+        harness.operate(&mut operation::focusable::focus(Id::new("skip")));
+
+        assert_eq!(focused(&mut harness), Some(Id::new("skip")));
+        assert_eq!(
+            focus_order(&mut harness, focus_next_by_index, 1),
+            [Some(Id::new("a"))]
+        );
+    }
+
+    #[test]
+    fn focus_next_no_wrap_stops_at_the_last_widget() {
+        let mut harness = harness(&["a", "b"]);
+        let boundary = || Id::new("boundary");
+
+        assert!(harness.run(focus_next_no_wrap(boundary)).is_empty());
+        assert_eq!(focused(&mut harness), Some(Id::new("a")));
+
+        assert!(harness.run(focus_next_no_wrap(boundary)).is_empty());
+        assert_eq!(focused(&mut harness), Some(Id::new("b")));
+
+        assert_eq!(harness.run(focus_next_no_wrap(boundary)), vec![boundary()]);
+        assert_eq!(focused(&mut harness), Some(Id::new("b")));
+    }
+
+    #[test]
+    fn focus_previous_no_wrap_stops_at_the_first_widget() {
+        let mut harness = harness(&["a", "b"]);
+        let boundary = || Id::new("boundary");
+
+        assert!(harness.run(focus_previous_no_wrap(boundary)).is_empty());
+        assert_eq!(focused(&mut harness), Some(Id::new("b")));
+
+        assert!(harness.run(focus_previous_no_wrap(boundary)).is_empty());
+        assert_eq!(focused(&mut harness), Some(Id::new("a")));
+
+        assert_eq!(
+            harness.run(focus_previous_no_wrap(boundary)),
+            vec![boundary()]
+        );
+        assert_eq!(focused(&mut harness), Some(Id::new("a")));
+    }
+
+    #[test]
+    fn no_wrap_reaches_the_boundary_without_focusable_widgets() {
+        let mut harness = harness(&[]);
+        let boundary = || Id::new("boundary");
+
+        assert_eq!(harness.run(focus_next_no_wrap(boundary)), vec![boundary()]);
+        assert_eq!(
+            harness.run(focus_previous_no_wrap(boundary)),
+            vec![boundary()]
+        );
+        assert_eq!(focused(&mut harness), None);
+    }
+
+    #[test]
+    fn focus_first_within_focuses_the_first_descendant() {
+        let mut harness = dialog();
+
+        harness.operate(&mut operation::focusable::focus(Id::new("before")));
+
+        assert_eq!(
+            harness.run(focus_first_within(Id::new("dialog"), |id| id)),
+            vec![Id::new("a")]
+        );
+        assert_eq!(focused(&mut harness), Some(Id::new("a")));
+    }
+
+    #[test]
+    fn focus_first_within_does_nothing_without_the_container() {
+        let mut harness = dialog();
+
+        harness.operate(&mut operation::focusable::focus(Id::new("before")));
+
+        assert!(
+            harness
+                .run(focus_first_within(Id::new("missing"), |id| id))
+                .is_empty()
+        );
+        assert_eq!(focused(&mut harness), Some(Id::new("before")));
+    }
+
+    #[test]
+    fn focus_next_within_wraps_inside_the_container() {
+        let mut harness = dialog();
+
+        harness.operate(&mut operation::focusable::focus(Id::new("before")));
+
+        assert_eq!(
+            focus_order(
+                &mut harness,
+                || focus_next_within(Id::new("dialog")),
+                3
+            ),
+            ["a", "b", "a"].map(|id| Some(Id::new(id)))
+        );
+    }
+
+    #[test]
+    fn focus_previous_within_wraps_inside_the_container() {
+        let mut harness = dialog();
+
+        harness.operate(&mut operation::focusable::focus(Id::new("after")));
+
+        assert_eq!(
+            focus_order(
+                &mut harness,
+                || focus_previous_within(Id::new("dialog")),
+                3
+            ),
+            ["b", "a", "b"].map(|id| Some(Id::new(id)))
+        );
+    }
+
+    #[test]
+    fn set_enabled_disables_and_blurs_a_focused_button() {
+        let mut harness = harness(&["a", "b"]);
+
+        harness.operate(&mut operation::focusable::focus(Id::new("a")));
+
+        let _ = harness.run::<()>(set_enabled(Id::new("a"), false));
+
+        assert_eq!(status(&mut harness, "a"), button::Status::Disabled);
+        assert_eq!(focused(&mut harness), None);
+
+        let _ = harness.run::<()>(set_enabled(Id::new("a"), true));
+
+        assert_eq!(status(&mut harness, "a"), button::Status::Active);
+    }
+
+    #[test]
+    fn set_enabled_cannot_enable_a_button_disabled_by_its_builder() {
+        let mut harness = Harness::new(
+            column![Button::new(text("off")).id(Id::new("off"))],
+            Size::new(200.0, 400.0),
+        );
+
+        let _ = harness.run::<()>(set_enabled(Id::new("off"), true));
+        harness.operate(&mut operation::focusable::focus(Id::new("off")));
+
+        assert_eq!(status(&mut harness, "off"), button::Status::Disabled);
+        assert_eq!(focused(&mut harness), None);
+    }
+
+    #[test]
+    fn press_publishes_the_message_on_the_next_event() {
+        let mut harness = harness(&["a", "b"]);
+
+        let _ = harness.run::<()>(press(Id::new("b")));
+
+        assert!(harness.messages().is_empty());
+
+        harness.redraw(Instant::now());
+
+        assert_eq!(harness.messages(), vec![Id::new("b")]);
+
+        harness.redraw(Instant::now());
+
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn press_is_ignored_by_a_disabled_button() {
+        let mut harness = harness(&["a"]);
+
+        let _ = harness.run::<()>(set_enabled(Id::new("a"), false));
+        let _ = harness.run::<()>(press(Id::new("a")));
+        harness.redraw(Instant::now());
+
+        let _ = harness.run::<()>(set_enabled(Id::new("a"), true));
+        harness.redraw(Instant::now());
+
+        assert!(harness.messages().is_empty());
+    }
+}
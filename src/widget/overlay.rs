@@ -1,3 +1,7 @@
 //! Overlay widgets for displaying content above other widgets.
 
+#[cfg(any(feature = "pick_list", feature = "text_input"))]
 pub mod menu;
+
+#[cfg(any(feature = "column", feature = "row"))]
+pub(crate) mod drag_preview;
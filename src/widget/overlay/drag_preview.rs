@@ -0,0 +1,89 @@
+//! Display an element under the cursor while an item is dragged.
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{Element, Point, Size};
+
+/// An overlay that shows a preview of a dragged item at the given position,
+/// kept within the window.
+///
+/// The preview is not interactive: it ignores every event and leaves the
+/// cursor to the widgets below.
+pub(crate) struct DragPreview<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    tree: Tree,
+    position: Point,
+}
+
+impl<'a, Message, Theme, Renderer> DragPreview<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`DragPreview`] of the given content, with its top-left
+    /// corner at `position`, in absolute coordinates.
+    pub(crate) fn new(
+        content: Element<'a, Message, Theme, Renderer>,
+        position: Point,
+    ) -> Self {
+        let tree = Tree::new(&content);
+
+        Self {
+            content,
+            tree,
+            position,
+        }
+    }
+
+    /// Turns the [`DragPreview`] into an overlay [`overlay::Element`].
+    pub(crate) fn overlay<'b>(
+        self,
+    ) -> overlay::Element<'b, Message, Theme, Renderer>
+    where
+        'a: 'b,
+        Message: 'b,
+        Theme: 'b,
+        Renderer: 'b,
+    {
+        overlay::Element::new(Box::new(self))
+    }
+}
+
+impl<Message, Theme, Renderer> crate::core::Overlay<Message, Theme, Renderer>
+    for DragPreview<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let node = self.content.as_widget_mut().layout(
+            &mut self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+
+        let size = node.size();
+
+        node.move_to(Point::new(
+            self.position.x.min(bounds.width - size.width).max(0.0),
+            self.position.y.min(bounds.height - size.height).max(0.0),
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.with_layer(bounds, |renderer| {
+            self.content.as_widget().draw(
+                &self.tree, renderer, theme, style, layout, cursor, &bounds,
+            );
+        });
+    }
+}
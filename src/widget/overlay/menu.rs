@@ -7,6 +7,8 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -16,6 +18,23 @@ use crate::core::{
 use crate::core::{Element, Shell, Widget};
 use crate::scrollable::{self, Scrollable};
 
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// The horizontal space between the icon of an option and its label.
+pub(crate) const ICON_SPACING: f32 = 8.0;
+
+/// The number of rows laid out beyond each edge of the viewport of a
+/// [`Menu`], so scrolling a little does not need a new layout.
+const ROW_BUFFER: usize = 20;
+
+/// The indentation of the options of a [`Menu`] with group headers.
+pub(crate) const GROUP_INDENT: f32 = 12.0;
+
+/// The icon of an option of a [`Menu`], along with its widget tree.
+type OptionIcon<'a, 'b, Message, Theme, Renderer> =
+    (&'a mut Element<'b, Message, Theme, Renderer>, &'a mut Tree);
+
 /// A list of selectable options.
 pub struct Menu<
     'a,
@@ -27,7 +46,6 @@ pub struct Menu<
 > where
     Theme: Catalog,
     Renderer: text::Renderer,
-    'b: 'a,
 {
     state: &'a mut State,
     options: &'a [T],
@@ -35,12 +53,20 @@ pub struct Menu<
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    label: Option<&'a dyn Fn(&T) -> String>,
+    icons: Vec<Option<OptionIcon<'a, 'b, Message, Theme, Renderer>>>,
+    headers: Cow<'a, [(usize, String)]>,
+    reasons: Cow<'a, [Option<String>]>,
+    max_visible: Option<usize>,
+    placement: Placement,
     width: f32,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    scrollbar_style: Option<&'a ScrollbarStyleFn<'b, Theme>>,
+    is_scrollbar_embedded: bool,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -71,12 +97,20 @@ where
             hovered_option,
             on_selected: Box::new(on_selected),
             on_option_hovered,
+            label: None,
+            icons: Vec::new(),
+            headers: Cow::Borrowed(&[]),
+            reasons: Cow::Borrowed(&[]),
+            max_visible: None,
+            placement: Placement::default(),
             width: 0.0,
             padding: Padding::ZERO,
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::default(),
             font: None,
+            scrollbar_style: None,
+            is_scrollbar_embedded: false,
             class,
         }
     }
@@ -120,8 +154,92 @@ where
         self
     }
 
-    /// Turns the [`Menu`] into an overlay [`Element`] at the given target
-    /// position.
+    /// Sets the function producing the label of each option of the [`Menu`].
+    ///
+    /// By default, options are labeled with their [`ToString`] output.
+    pub fn label(mut self, label: &'a dyn Fn(&T) -> String) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// Sets the icons of the options of the [`Menu`], drawn before their
+    /// labels, along with their widget trees.
+    ///
+    /// There is one entry per option, in the same order. Icons are laid out
+    /// within the line height of the text, so they never make a row taller.
+    /// They are not interactive.
+    pub fn icons(
+        mut self,
+        icons: Vec<Option<OptionIcon<'a, 'b, Message, Theme, Renderer>>>,
+    ) -> Self {
+        self.icons = icons;
+        self
+    }
+
+    /// Sets the group headers of the [`Menu`], as pairs of the index of
+    /// the first option of a group and its name, sorted by index.
+    ///
+    /// Headers are drawn as rows of their own, before the first option of
+    /// their group, and cannot be hovered or selected. Options are indented
+    /// when there are any headers.
+    pub fn headers(
+        mut self,
+        headers: impl Into<Cow<'a, [(usize, String)]>>,
+    ) -> Self {
+        self.headers = headers.into();
+        self
+    }
+
+    /// Sets the reasons why the options of the [`Menu`] are disabled, with
+    /// one entry per option, in the same order.
+    ///
+    /// The reason of a disabled option is drawn as a trailing label in its
+    /// row. Reasons of enabled options are ignored.
+    pub fn disabled_reasons(
+        mut self,
+        reasons: impl Into<Cow<'a, [Option<String>]>>,
+    ) -> Self {
+        self.reasons = reasons.into();
+        self
+    }
+
+    /// Sets the maximum number of rows shown at once by the [`Menu`],
+    /// headers included.
+    ///
+    /// The [`Menu`] scrolls through the rest of its options. By default, it
+    /// grows to show all of them, as long as they fit in the window.
+    pub fn max_visible(mut self, rows: usize) -> Self {
+        self.max_visible = Some(rows);
+        self
+    }
+
+    /// Sets the [`Placement`] of the [`Menu`] relative to its target.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`Menu`].
+    ///
+    /// By default, the [`Catalog::default_scrollable`] class is used.
+    pub fn scrollbar_style(
+        mut self,
+        style: &'a ScrollbarStyleFn<'b, Theme>,
+    ) -> Self {
+        self.scrollbar_style = Some(style);
+        self
+    }
+
+    /// Sets whether the scrollbar of the [`Menu`] is embedded next to the
+    /// options, instead of floating over them.
+    ///
+    /// An embedded scrollbar reserves its width, so it never covers the text
+    /// of the options.
+    pub fn scrollbar_embedded(mut self, is_embedded: bool) -> Self {
+        self.is_scrollbar_embedded = is_embedded;
+        self
+    }
+
     /// Check if an option at the given index is disabled.
     pub fn is_disabled(&self, index: usize) -> bool {
         self.disabled
@@ -131,6 +249,8 @@ where
             .unwrap_or(false)
     }
 
+    /// Turns the [`Menu`] into an overlay [`Element`] at the given target
+    /// position.
     ///
     /// The `target_height` will be used to display the menu either on top
     /// of the target or under it, depending on the screen position and the
@@ -156,6 +276,7 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    is_revealing: bool,
 }
 
 impl State {
@@ -163,16 +284,44 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            is_revealing: false,
         }
     }
 }
 
+impl State {
+    /// Scrolls the hovered option of the [`Menu`] to the middle of its
+    /// viewport, once, on its next layout.
+    ///
+    /// Nothing is scrolled if no option is hovered.
+    pub fn reveal(&mut self) {
+        self.is_revealing = true;
+    }
+}
+
 impl Default for State {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// The side of its target where a [`Menu`] opens.
+///
+/// Whatever the side, the [`Menu`] is limited to the room between its target
+/// and the edge of the window on that side, and scrolls through the options
+/// that do not fit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Placement {
+    /// Below the target, unless there is more room above it; e.g. when the
+    /// target is near the bottom of the window.
+    #[default]
+    Auto,
+    /// Always above the target.
+    Above,
+    /// Always below the target.
+    Below,
+}
+
 struct Overlay<'a, 'b, Message, Theme, Renderer>
 where
     Theme: Catalog,
@@ -181,9 +330,16 @@ where
     position: Point,
     viewport: Rectangle,
     tree: &'a mut Tree,
+    is_revealing: &'a mut bool,
+    revealed_row: Option<usize>,
     list: Scrollable<'a, Message, Theme, Renderer>,
     width: f32,
     target_height: f32,
+    max_visible: Option<usize>,
+    placement: Placement,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    padding: Padding,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -211,21 +367,48 @@ where
             hovered_option,
             on_selected,
             on_option_hovered,
+            label,
+            icons,
+            headers,
+            reasons,
+            max_visible,
+            placement,
             width,
             padding,
             font,
             text_size,
             text_line_height,
             text_shaping,
+            scrollbar_style,
+            is_scrollbar_embedded,
             class,
         } = menu;
 
+        let revealed_row = hovered_option.map(|index| {
+            index
+                + headers
+                    .iter()
+                    .take_while(|(first, _)| *first <= index)
+                    .count()
+        });
+
+        let scrollbar = if is_scrollbar_embedded {
+            scrollable::Scrollbar::default().spacing(0)
+        } else {
+            scrollable::Scrollbar::default()
+        };
+
         let list = Scrollable::new(List {
             options,
             disabled,
             hovered_option,
             on_selected,
             on_option_hovered,
+            label,
+            icons,
+            icon_width: 0.0,
+            headers,
+            reasons,
             font,
             text_size,
             text_line_height,
@@ -233,7 +416,12 @@ where
             padding,
             class,
         })
-        .height(menu_height);
+        .direction(scrollable::Direction::Vertical(scrollbar))
+        .height(menu_height)
+        .class(match scrollbar_style {
+            Some(style) => Theme::scrollable_with(style),
+            None => Theme::default_scrollable(),
+        });
 
         state.tree.diff(&list as &dyn Widget<_, _, _>);
 
@@ -241,9 +429,16 @@ where
             position,
             viewport,
             tree: &mut state.tree,
+            is_revealing: &mut state.is_revealing,
+            revealed_row,
             list,
             width,
             target_height,
+            max_visible,
+            placement,
+            text_size,
+            text_line_height,
+            padding,
             class,
         }
     }
@@ -260,23 +455,71 @@ where
             bounds.height - (self.position.y + self.target_height);
         let space_above = self.position.y;
 
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let option_height =
+            f32::from(self.text_line_height.to_absolute(text_size))
+                + self.padding.y();
+
+        let max_height = self
+            .max_visible
+            .map_or(f32::INFINITY, |rows| option_height * rows as f32);
+
+        let is_below = match self.placement {
+            Placement::Auto => space_below > space_above,
+            Placement::Above => false,
+            Placement::Below => true,
+        };
+
         let limits = layout::Limits::new(
             Size::ZERO,
             Size::new(
                 bounds.width - self.position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
+                if is_below { space_below } else { space_above }
+                    .min(max_height),
             ),
         )
         .width(self.width);
 
+        // The list only lays out the icons of the rows around its viewport,
+        // which it learns once scrolled; until then, assume its top or the
+        // revealed row
+        if let Some(list) = self.tree.children.first_mut() {
+            let state = list.state.downcast_mut::<ListState>();
+            let rows = (limits.max().height / option_height).ceil() as usize;
+
+            if *self.is_revealing
+                && let Some(row) = self.revealed_row
+            {
+                let start = row.saturating_sub(rows / 2);
+
+                state.visible = Some(start..start + rows);
+            } else if state.visible.is_none() {
+                state.visible = Some(0..rows);
+            }
+        }
+
         let node = self.list.layout(self.tree, renderer, &limits);
         let size = node.size();
 
-        node.move_to(if space_below > space_above {
+        if *self.is_revealing {
+            *self.is_revealing = false;
+
+            if let Some(row) = self.revealed_row {
+                self.list.operate(
+                    self.tree,
+                    Layout::new(&node),
+                    renderer,
+                    &mut Reveal {
+                        y: option_height * row as f32,
+                        height: option_height,
+                    },
+                );
+            }
+        }
+
+        node.move_to(if is_below {
             self.position + Vector::new(0.0, self.target_height)
         } else {
             self.position - Vector::new(0.0, size.height)
@@ -342,6 +585,33 @@ where
     }
 }
 
+/// An [`Operation`] that scrolls a row of a [`Menu`] to the middle of its
+/// viewport.
+struct Reveal {
+    y: f32,
+    height: f32,
+}
+
+impl<T> Operation<T> for Reveal {
+    fn scrollable(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+        state: &mut dyn operation::Scrollable,
+    ) {
+        state.scroll_to(operation::scrollable::AbsoluteOffset {
+            x: None,
+            y: Some((self.y - (bounds.height - self.height) / 2.0).max(0.0)),
+        });
+    }
+
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+}
+
 struct List<'a, 'b, T, Message, Theme, Renderer>
 where
     Theme: Catalog,
@@ -352,6 +622,11 @@ where
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    label: Option<&'a dyn Fn(&T) -> String>,
+    icons: Vec<Option<OptionIcon<'a, 'b, Message, Theme, Renderer>>>,
+    icon_width: f32,
+    headers: Cow<'a, [(usize, String)]>,
+    reasons: Cow<'a, [Option<String>]>,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
@@ -372,10 +647,96 @@ where
             .copied()
             .unwrap_or(false)
     }
+
+    /// Returns the number of rows of the [`List`], headers included.
+    fn rows(&self) -> usize {
+        self.options.len() + self.headers.len()
+    }
+
+    /// Returns the number of options before the given row.
+    fn options_before(&self, row: usize) -> usize {
+        let headers = self
+            .headers
+            .iter()
+            .enumerate()
+            .take_while(|(i, (index, _))| index + i < row)
+            .count();
+
+        (row - headers).min(self.options.len())
+    }
+
+    /// Returns the given rows along with [`ROW_BUFFER`] rows on each side.
+    fn buffered(&self, rows: Range<usize>) -> Range<usize> {
+        rows.start.saturating_sub(ROW_BUFFER)
+            ..(rows.end + ROW_BUFFER).min(self.rows())
+    }
+
+    /// Returns the rows intersecting the given viewport.
+    fn visible(
+        &self,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        option_height: f32,
+    ) -> Range<usize> {
+        let offset = viewport.y - bounds.y;
+
+        (offset / option_height) as usize
+            ..((offset + viewport.height) / option_height).ceil() as usize
+    }
+
+    /// Returns the index of the option at the given row, or `None` if the
+    /// row is a header or out of bounds.
+    fn option_at(&self, row: usize) -> Option<usize> {
+        let mut headers = 0;
+
+        for (i, (index, _)) in self.headers.iter().enumerate() {
+            let header_row = index + i;
+
+            if row == header_row {
+                return None;
+            } else if row < header_row {
+                break;
+            }
+
+            headers += 1;
+        }
+
+        let index = row - headers;
+
+        (index < self.options.len()).then_some(index)
+    }
+
+    /// Returns the name of the header at the given row, if any.
+    fn header_at(&self, row: usize) -> Option<&str> {
+        self.headers
+            .iter()
+            .enumerate()
+            .find(|(i, (index, _))| index + i == row)
+            .map(|(_, (_, name))| name.as_str())
+    }
+
+    /// Returns the indentation of the options.
+    fn indent(&self) -> f32 {
+        if self.headers.is_empty() {
+            0.0
+        } else {
+            GROUP_INDENT
+        }
+    }
 }
 
 struct ListState {
     is_hovered: Option<bool>,
+    /// The rows in the viewport, as of the last event.
+    visible: Option<Range<usize>>,
+    /// The rows with their icons laid out.
+    rows: Range<usize>,
+    /// The options with their icons laid out, in the same order as the
+    /// children of the layout.
+    icons: Range<usize>,
+    /// The width of the widest icon laid out so far, which keeps labels
+    /// from shifting while scrolling.
+    icon_width: f32,
 }
 
 impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -386,11 +747,17 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<Option<bool>>()
+        tree::Tag::of::<ListState>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(ListState { is_hovered: None })
+        tree::State::new(ListState {
+            is_hovered: None,
+            visible: None,
+            rows: 0..0,
+            icons: 0..0,
+            icon_width: 0.0,
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -402,7 +769,7 @@ where
 
     fn layout(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
@@ -417,13 +784,65 @@ where
             let intrinsic = Size::new(
                 0.0,
                 (f32::from(text_line_height) + self.padding.y())
-                    * self.options.len() as f32,
+                    * self.rows() as f32,
             );
 
             limits.resolve(Length::Fill, Length::Shrink, intrinsic)
         };
 
-        layout::Node::new(size)
+        let option_height = f32::from(text_line_height) + self.padding.y();
+
+        let icon_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, f32::from(text_line_height)),
+        );
+
+        // Only the icons of the rows around the viewport are laid out, so
+        // the layout does not grow with the number of options
+        let state = tree.state.downcast_mut::<ListState>();
+        let rows = self.buffered(state.visible.clone().unwrap_or_default());
+        let options = self.options_before(rows.start).min(self.icons.len())
+            ..self.options_before(rows.end).min(self.icons.len());
+
+        let mut headers = self.headers.iter().peekable();
+        let icon_rows = options.clone().scan(0, |before, i| {
+            while headers.next_if(|(first, _)| *first <= i).is_some() {
+                *before += 1;
+            }
+
+            Some(i + *before)
+        });
+        let x = self.padding.left + self.indent();
+
+        let icons: Vec<_> = self.icons[options.clone()]
+            .iter_mut()
+            .zip(icon_rows)
+            .map(|(icon, row)| {
+                let Some((icon, tree)) = icon else {
+                    return layout::Node::default();
+                };
+
+                let node =
+                    icon.as_widget_mut().layout(tree, renderer, &icon_limits);
+                let height = node.size().height;
+
+                node.move_to(Point::new(
+                    x,
+                    option_height * row as f32 + (option_height - height) / 2.0,
+                ))
+            })
+            .collect();
+
+        state.icon_width =
+            icons.iter().fold(state.icon_width, |width, icon| {
+                f32::max(width, icon.size().width)
+            });
+        state.rows = rows;
+        state.icons = options;
+
+        self.icon_width = state.icon_width;
+
+        layout::Node::with_children(size, icons)
     }
 
     fn update(
@@ -435,7 +854,7 @@ where
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
@@ -460,23 +879,25 @@ where
                         f32::from(self.text_line_height.to_absolute(text_size))
                             + self.padding.y();
 
-                    let new_hovered_option =
-                        (cursor_position.y / option_height) as usize;
+                    let new_hovered_option = self
+                        .option_at((cursor_position.y / option_height) as usize)
+                        .filter(|&index| !self.is_disabled(index));
 
-                    if *self.hovered_option != Some(new_hovered_option)
-                        && !self.is_disabled(new_hovered_option)
-                        && let Some(option) =
-                            self.options.get(new_hovered_option)
-                    {
-                        if let Some(on_option_hovered) = self.on_option_hovered
+                    if let Some(new_hovered_option) = new_hovered_option {
+                        if *self.hovered_option != Some(new_hovered_option)
+                            && let Some(option) =
+                                self.options.get(new_hovered_option)
                         {
-                            shell.publish(on_option_hovered(option.clone()));
+                            if let Some(on_option_hovered) =
+                                self.on_option_hovered
+                            {
+                                shell
+                                    .publish(on_option_hovered(option.clone()));
+                            }
+
+                            shell.request_redraw();
                         }
 
-                        shell.request_redraw();
-                    }
-
-                    if !self.is_disabled(new_hovered_option) {
                         *self.hovered_option = Some(new_hovered_option);
                     }
                 }
@@ -493,9 +914,13 @@ where
                         f32::from(self.text_line_height.to_absolute(text_size))
                             + self.padding.y();
 
-                    let index = (cursor_position.y / option_height) as usize;
+                    let index = self.option_at(
+                        (cursor_position.y / option_height) as usize,
+                    );
 
-                    if !self.is_disabled(index) {
+                    if let Some(index) = index
+                        && !self.is_disabled(index)
+                    {
                         *self.hovered_option = Some(index);
 
                         if let Some(option) = self.options.get(index) {
@@ -510,6 +935,23 @@ where
 
         let state = tree.state.downcast_mut::<ListState>();
 
+        // Lay out the icons of the rows scrolled into view
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let option_height =
+            f32::from(self.text_line_height.to_absolute(text_size))
+                + self.padding.y();
+        let visible = self.visible(layout.bounds(), viewport, option_height);
+
+        if !self.icons.is_empty()
+            && (visible.start < state.rows.start
+                || visible.end.min(self.rows()) > state.rows.end)
+        {
+            shell.invalidate_layout();
+        }
+
+        state.visible = Some(visible);
+
         if let Event::Window(window::Event::RedrawRequested(_now)) = event {
             state.is_hovered = Some(cursor.is_over(layout.bounds()));
         } else if state.is_hovered.is_some_and(|is_hovered| {
@@ -535,9 +977,12 @@ where
                 f32::from(self.text_line_height.to_absolute(text_size))
                     + self.padding.y();
 
-            let hovered_option = (cursor_position.y / option_height) as usize;
+            let hovered_option =
+                self.option_at((cursor_position.y / option_height) as usize);
 
-            if !self.is_disabled(hovered_option) {
+            if let Some(hovered_option) = hovered_option
+                && !self.is_disabled(hovered_option)
+            {
                 return mouse::Interaction::Pointer;
             }
         }
@@ -547,12 +992,12 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        defaults: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         let style = Catalog::style(theme, self.class);
@@ -564,24 +1009,64 @@ where
             f32::from(self.text_line_height.to_absolute(text_size))
                 + self.padding.y();
 
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
-
-        let visible_options = &self.options[start..end.min(self.options.len())];
+        // Rows have a fixed height, so only the ones in the viewport are
+        // drawn, no matter how many options there are
+        let visible = self.visible(bounds, viewport, option_height);
 
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
-            let is_disabled = self.is_disabled(i);
+        let state = tree.state.downcast_ref::<ListState>();
+        let icon_layouts: Vec<_> = layout.children().collect();
+        let indent = self.indent();
+        let label_offset = if self.icon_width > 0.0 {
+            self.icon_width + ICON_SPACING
+        } else {
+            0.0
+        };
 
+        for row in visible.start..visible.end.min(self.rows()) {
             let bounds = Rectangle {
                 x: bounds.x,
-                y: bounds.y + (option_height * i as f32),
+                y: bounds.y + (option_height * row as f32),
                 width: bounds.width,
                 height: option_height,
             };
 
+            let Some(i) = self.option_at(row) else {
+                if let Some(name) = self.header_at(row) {
+                    renderer.fill_text(
+                        Text {
+                            content: name.to_owned(),
+                            bounds: Size::new(f32::INFINITY, bounds.height),
+                            size: text_size,
+                            line_height: self.text_line_height,
+                            font: self
+                                .font
+                                .unwrap_or_else(|| renderer.default_font()),
+                            align_x: text::Alignment::Default,
+                            align_y: alignment::Vertical::Center,
+                            shaping: self.text_shaping,
+                            wrapping: text::Wrapping::default(),
+                        },
+                        Point::new(
+                            bounds.x + self.padding.left,
+                            bounds.center_y(),
+                        ),
+                        style.header_text_color,
+                        *viewport,
+                    );
+                }
+
+                continue;
+            };
+
+            let option = &self.options[i];
+            let icon_layout = state
+                .icons
+                .contains(&i)
+                .then(|| icon_layouts.get(i - state.icons.start))
+                .flatten();
+            let is_selected = *self.hovered_option == Some(i);
+            let is_disabled = self.is_disabled(i);
+
             if is_selected && !is_disabled {
                 renderer.fill_quad(
                     renderer::Quad {
@@ -610,9 +1095,26 @@ where
                 );
             }
 
+            if let Some(Some((icon, tree))) = self.icons.get(i)
+                && let Some(&icon_layout) = icon_layout
+            {
+                icon.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    defaults,
+                    icon_layout,
+                    cursor,
+                    &bounds,
+                );
+            }
+
             renderer.fill_text(
                 Text {
-                    content: option.to_string(),
+                    content: match self.label {
+                        Some(label) => label(option),
+                        None => option.to_string(),
+                    },
                     bounds: Size::new(f32::INFINITY, bounds.height),
                     size: text_size,
                     line_height: self.text_line_height,
@@ -622,7 +1124,10 @@ where
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                Point::new(
+                    bounds.x + self.padding.left + indent + label_offset,
+                    bounds.center_y(),
+                ),
                 if is_disabled {
                     style.disabled_text_color
                 } else if is_selected {
@@ -632,6 +1137,30 @@ where
                 },
                 *viewport,
             );
+
+            if is_disabled && let Some(Some(reason)) = self.reasons.get(i) {
+                renderer.fill_text(
+                    Text {
+                        content: reason.clone(),
+                        bounds: Size::new(f32::INFINITY, bounds.height),
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font: self
+                            .font
+                            .unwrap_or_else(|| renderer.default_font()),
+                        align_x: text::Alignment::Right,
+                        align_y: alignment::Vertical::Center,
+                        shaping: self.text_shaping,
+                        wrapping: text::Wrapping::default(),
+                    },
+                    Point::new(
+                        bounds.x + bounds.width - self.padding.right,
+                        bounds.center_y(),
+                    ),
+                    style.disabled_text_color,
+                    *viewport,
+                );
+            }
         }
     }
 }
@@ -668,6 +1197,8 @@ pub struct Style {
     pub disabled_text_color: Color,
     /// The background [`Color`] of a disabled option in the menu.
     pub disabled_background: Background,
+    /// The text [`Color`] of a group header in the menu.
+    pub header_text_color: Color,
 }
 
 /// The theme catalog of a [`Menu`].
@@ -683,6 +1214,19 @@ pub trait Catalog: scrollable::Catalog {
         <Self as scrollable::Catalog>::default()
     }
 
+    /// The class for the scrollable of the [`Menu`], styled with the given
+    /// function.
+    ///
+    /// By default, the function is ignored and
+    /// [`Catalog::default_scrollable`] is used.
+    fn scrollable_with<'a>(
+        style: &'a ScrollbarStyleFn<'_, Self>,
+    ) -> <Self as scrollable::Catalog>::Class<'a> {
+        let _ = style;
+
+        Self::default_scrollable()
+    }
+
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
 }
@@ -690,6 +1234,10 @@ pub trait Catalog: scrollable::Catalog {
 /// A styling function for a [`Menu`].
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
+/// A styling function for the scrollbar of a [`Menu`].
+pub type ScrollbarStyleFn<'a, Theme> =
+    Box<dyn Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a>;
+
 impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
@@ -697,6 +1245,12 @@ impl Catalog for Theme {
         Box::new(default)
     }
 
+    fn scrollable_with<'a>(
+        style: &'a ScrollbarStyleFn<'_, Self>,
+    ) -> <Self as scrollable::Catalog>::Class<'a> {
+        Box::new(move |theme, status| style(theme, status))
+    }
+
     fn style(&self, class: &StyleFn<'_, Self>) -> Style {
         class(self)
     }
@@ -718,5 +1272,170 @@ pub fn default(theme: &Theme) -> Style {
         selected_background: palette.primary.strong.color.into(),
         disabled_text_color: palette.background.strong.color,
         disabled_background: palette.background.weak.color.into(),
+        header_text_color: palette.secondary.base.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPTIONS: usize = 100;
+    const HEIGHT: f32 = 200.0;
+
+    /// Returns the scroll offset of a [`Menu`] hovering the given option,
+    /// after its first layout.
+    fn offset(state: &mut State, hovered_option: Option<usize>) -> f32 {
+        struct Offset(f32);
+
+        impl Operation for Offset {
+            fn scrollable(
+                &mut self,
+                _id: Option<&widget::Id>,
+                _bounds: Rectangle,
+                _content_bounds: Rectangle,
+                translation: Vector,
+                _state: &mut dyn operation::Scrollable,
+            ) {
+                self.0 = translation.y;
+            }
+
+            fn traverse(
+                &mut self,
+                operate: &mut dyn FnMut(&mut dyn Operation),
+            ) {
+                operate(self);
+            }
+        }
+
+        let options: Vec<String> =
+            (0..OPTIONS).map(|i| format!("Option {i}")).collect();
+        let mut hovered_option = hovered_option;
+        let class = <Theme as Catalog>::default();
+
+        let menu: Menu<'_, '_, _, (), Theme, ()> = Menu::new(
+            state,
+            &options,
+            &mut hovered_option,
+            |_| (),
+            None,
+            None,
+            &class,
+        )
+        .width(100.0);
+
+        let mut overlay = Overlay::new(
+            Point::ORIGIN,
+            Rectangle::with_size(Size::new(100.0, HEIGHT)),
+            menu,
+            0.0,
+            Length::Shrink,
+        );
+
+        let node = crate::core::Overlay::layout(
+            &mut overlay,
+            &(),
+            Size::new(100.0, HEIGHT),
+        );
+
+        let mut offset = Offset(0.0);
+        overlay.list.operate(
+            overlay.tree,
+            Layout::new(&node),
+            &(),
+            &mut offset,
+        );
+
+        offset.0
+    }
+
+    fn option_height() -> f32 {
+        f32::from(
+            text::LineHeight::default()
+                .to_absolute(<() as text::Renderer>::default_size(&())),
+        )
+    }
+
+    #[test]
+    fn reveal_scrolls_to_an_option_near_the_end() {
+        let mut state = State::new();
+        state.reveal();
+
+        let offset = offset(&mut state, Some(OPTIONS - 5));
+        let y = option_height() * (OPTIONS - 5) as f32;
+
+        assert!(offset > 0.0);
+        assert!(offset <= y && y + option_height() <= offset + HEIGHT);
+    }
+
+    #[test]
+    fn reveal_without_a_hovered_option_stays_at_the_top() {
+        let mut state = State::new();
+        state.reveal();
+
+        assert_eq!(offset(&mut state, None), 0.0);
+    }
+
+    #[test]
+    fn reveal_happens_once() {
+        let mut state = State::new();
+        state.reveal();
+
+        assert!(offset(&mut state, Some(OPTIONS - 5)) > 0.0);
+
+        // Start from a fresh list, so only a second reveal could scroll it
+        state.tree = Tree::empty();
+
+        assert_eq!(offset(&mut state, Some(OPTIONS - 5)), 0.0);
+    }
+
+    #[test]
+    fn only_icons_around_the_viewport_are_laid_out() {
+        let options: Vec<String> =
+            (0..OPTIONS).map(|i| format!("Option {i}")).collect();
+        let mut icons: Vec<Element<'_, (), Theme, ()>> = options
+            .iter()
+            .map(|_| iced_widget::text("*").into())
+            .collect();
+        let mut trees: Vec<Tree> = icons.iter().map(Tree::new).collect();
+        let class = <Theme as Catalog>::default();
+
+        for row in [0, OPTIONS - 5] {
+            let mut state = State::new();
+            let mut hovered_option = Some(row);
+            state.reveal();
+
+            let menu: Menu<'_, '_, _, (), Theme, ()> = Menu::new(
+                &mut state,
+                &options,
+                &mut hovered_option,
+                |_| (),
+                None,
+                None,
+                &class,
+            )
+            .width(100.0)
+            .icons(icons.iter_mut().zip(&mut trees).map(Some).collect());
+
+            let mut overlay = Overlay::new(
+                Point::ORIGIN,
+                Rectangle::with_size(Size::new(100.0, HEIGHT)),
+                menu,
+                0.0,
+                Length::Shrink,
+            );
+
+            let _ = crate::core::Overlay::layout(
+                &mut overlay,
+                &(),
+                Size::new(100.0, HEIGHT),
+            );
+
+            let list =
+                overlay.tree.children[0].state.downcast_ref::<ListState>();
+
+            assert!(list.icons.contains(&row));
+            assert!(list.icons.len() < OPTIONS / 2);
+        }
     }
 }
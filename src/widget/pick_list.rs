@@ -1,7 +1,9 @@
 //! Pick lists display a dropdown list of selectable options.
 //!
 //! This is a sweetened version of `iced`'s [`pick_list`] with support for
-//! disabling individual items via [`PickList::disabled`].
+//! disabling individual items via [`PickList::disabled`], for
+//! [`PickList::on_focus`] and [`PickList::on_blur`] messages, and for
+//! searching its options via [`PickList::searchable`].
 //!
 //! [`pick_list`]: https://docs.iced.rs/iced/widget/pick_list/
 //!
@@ -63,7 +65,10 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
+use crate::core::widget::Id;
+use crate::core::widget::operation::{Focusable, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -71,6 +76,8 @@ use crate::core::{
     Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 use crate::overlay::menu::{self, Menu};
+use crate::scrollable;
+use crate::widget::operation::WidgetState;
 use std::borrow::Borrow;
 use std::f32;
 
@@ -155,8 +162,18 @@ pub struct PickList<
     on_select: Box<dyn Fn(T) -> Message + 'a>,
     on_open: Option<Message>,
     on_close: Option<Message>,
+    on_focus: Option<Message>,
+    on_blur: Option<Message>,
+    id: Option<Id>,
     options: L,
     disabled: Option<Box<dyn Fn(&[T]) -> Vec<bool> + 'a>>,
+    disabled_reason: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    searchable: bool,
+    filter: Option<Box<dyn Fn(&T, &str) -> bool + 'a>>,
+    menu_options: Option<MenuOptions<T>>,
+    label: Option<Box<dyn Fn(&T) -> String + 'a>>,
+    icons: Vec<Option<Element<'a, Message, Theme, Renderer>>>,
+    group: Option<Box<dyn Fn(&T) -> Option<&str> + 'a>>,
     placeholder: Option<String>,
     selected: Option<V>,
     width: Length,
@@ -168,8 +185,12 @@ pub struct PickList<
     handle: Handle<Renderer::Font>,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
+    menu_scrollbar: Option<menu::ScrollbarStyleFn<'a, Theme>>,
+    menu_scrollbar_always_visible: bool,
     last_status: Option<Status>,
     menu_height: Length,
+    max_visible: Option<usize>,
+    menu_placement: menu::Placement,
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer>
@@ -193,12 +214,22 @@ where
             on_select: Box::new(on_select),
             on_open: None,
             on_close: None,
+            on_focus: None,
+            on_blur: None,
+            id: None,
             options,
             disabled: None,
+            disabled_reason: None,
+            searchable: false,
+            filter: None,
+            menu_options: None,
+            label: None,
+            icons: Vec::new(),
+            group: None,
             placeholder: None,
             selected,
             width: Length::Shrink,
-            padding: crate::widget::button::DEFAULT_PADDING,
+            padding: crate::iced_button::DEFAULT_PADDING,
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::default(),
@@ -206,11 +237,21 @@ where
             handle: Handle::default(),
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
+            menu_scrollbar: None,
+            menu_scrollbar_always_visible: false,
             last_status: None,
             menu_height: Length::Shrink,
+            max_visible: None,
+            menu_placement: menu::Placement::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`PickList`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
@@ -229,6 +270,86 @@ where
         self
     }
 
+    /// Sets a function that explains why an option is disabled.
+    ///
+    /// The reason is shown as a muted trailing label in the row of the
+    /// option. [`PickList::disabled`] alone decides which options are
+    /// disabled: reasons are only shown for those, and ignored for enabled
+    /// options.
+    pub fn disabled_reason(
+        mut self,
+        reason: impl Fn(&T) -> Option<String> + 'a,
+    ) -> Self {
+        self.disabled_reason = Some(Box::new(reason));
+        self
+    }
+
+    /// Sets whether the options of the [`PickList`] can be searched.
+    ///
+    /// When searchable, typing while the menu is open filters its options
+    /// with a case-insensitive substring match against their labels. Use
+    /// [`PickList::filter_with`] to provide a custom predicate.
+    ///
+    /// Otherwise, typing while the menu is open hovers the next enabled
+    /// option whose label starts with the typed characters.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Sets the predicate used to filter the options of a searchable
+    /// [`PickList`] against the typed query, e.g. for fuzzy or
+    /// accent-insensitive matching.
+    ///
+    /// The predicate receives an option and the current query, and returns
+    /// whether the option should be shown. It overrides the default substring
+    /// filter and makes the [`PickList`] searchable.
+    ///
+    /// Disabled options remain disabled when they match.
+    pub fn filter_with(mut self, f: impl Fn(&T, &str) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(f));
+        self.searchable = true;
+        self
+    }
+
+    /// Sets the function producing the label shown for an option, both in
+    /// the menu and for the selected value.
+    ///
+    /// By default, options are labeled with their [`ToString`] output. This
+    /// only changes how options are displayed and searched: selection and
+    /// [`PickList::disabled`] still operate on the options themselves.
+    pub fn label(mut self, label: impl Fn(&T) -> String + 'a) -> Self {
+        self.label = Some(Box::new(label));
+        self
+    }
+
+    /// Sets the function producing the icon of an option, drawn before its
+    /// label in the menu and for the selected value.
+    ///
+    /// Labels are aligned after the widest icon, so options without one
+    /// line up with the rest. Icons are laid out within the line height of
+    /// the text: larger icons are shrunk to fit, and never make the
+    /// [`PickList`] or a row of its menu taller. They are not interactive.
+    pub fn icon(
+        mut self,
+        icon: impl Fn(&T) -> Option<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.icons = self.options.borrow().iter().map(icon).collect();
+        self
+    }
+
+    /// Sets the function returning the group of an option, if any.
+    ///
+    /// The menu shows a header with the name of a group before each run of
+    /// consecutive options in it, and indents the options. Headers cannot be
+    /// hovered or selected, so they are skipped by type-ahead and are never
+    /// affected by [`PickList::disabled`]. When searching, headers are only
+    /// shown for groups with matching options.
+    pub fn groups(mut self, group: impl Fn(&T) -> Option<&str> + 'a) -> Self {
+        self.group = Some(Box::new(group));
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -241,6 +362,25 @@ where
         self
     }
 
+    /// Sets the maximum number of rows shown at once by the [`Menu`].
+    ///
+    /// Longer lists of options are scrolled through. By default, the
+    /// [`Menu`] grows to show every option that fits in the window.
+    pub fn max_visible(mut self, rows: usize) -> Self {
+        self.max_visible = Some(rows);
+        self
+    }
+
+    /// Sets the side of the [`PickList`] where its [`Menu`] opens.
+    ///
+    /// By default, the [`Menu`] opens below, unless there is more room
+    /// above; e.g. near the bottom of the window. If the chosen side lacks
+    /// room for every option, the [`Menu`] is shortened to fit and scrolls.
+    pub fn menu_placement(mut self, placement: menu::Placement) -> Self {
+        self.menu_placement = placement;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`PickList`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -281,17 +421,38 @@ where
     }
 
     /// Sets the message that will be produced when the [`PickList`] is opened.
+    ///
+    /// The menu opens when the [`PickList`] is clicked, or when
+    /// <kbd>Enter</kbd> or <kbd>Space</kbd> is pressed while it is focused.
     pub fn on_open(mut self, on_open: Message) -> Self {
         self.on_open = Some(on_open);
         self
     }
 
     /// Sets the message that will be produced when the [`PickList`] is closed.
+    ///
+    /// The menu closes when an option is selected, when clicking outside
+    /// of it, or when <kbd>Escape</kbd> is pressed. The message is produced
+    /// once per close, after the message of the selection.
     pub fn on_close(mut self, on_close: Message) -> Self {
         self.on_close = Some(on_close);
         self
     }
 
+    /// Sets the message that will be produced when the [`PickList`] is
+    /// focused.
+    pub fn on_focus(mut self, on_focus: Message) -> Self {
+        self.on_focus = Some(on_focus);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`PickList`] is
+    /// blurred.
+    pub fn on_blur(mut self, on_blur: Message) -> Self {
+        self.on_blur = Some(on_blur);
+        self
+    }
+
     /// Sets the style of the [`PickList`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -315,6 +476,34 @@ where
         self
     }
 
+    /// Sets the style of the scrollbar of the [`Menu`], shown when there are
+    /// more options than fit in its height.
+    ///
+    /// By default, the scrollbar is styled by the theme.
+    #[must_use]
+    pub fn menu_scrollbar(
+        mut self,
+        style: impl Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a,
+    ) -> Self {
+        self.menu_scrollbar = Some(Box::new(style));
+        self
+    }
+
+    /// Sets whether the scrollbar of the [`Menu`] is always visible next to
+    /// the options.
+    ///
+    /// By default, the scrollbar floats over the options, where it may cover
+    /// the end of long ones. When always visible, its width is reserved
+    /// instead, so it never overlaps the text of an option.
+    #[must_use]
+    pub fn menu_scrollbar_always_visible(
+        mut self,
+        always_visible: bool,
+    ) -> Self {
+        self.menu_scrollbar_always_visible = always_visible;
+        self
+    }
+
     /// Sets the style class of the [`PickList`].
     #[must_use]
     pub fn class(
@@ -334,6 +523,159 @@ where
         self.menu_class = class.into();
         self
     }
+
+    /// Filters the options of the [`PickList`] with the given query, when
+    /// searchable, and computes how the [`Menu`] shows them.
+    fn menu_options(&self, query: &str) -> MenuOptions<T> {
+        let options = self.options.borrow();
+        let disabled = self.disabled.as_ref().map(|f| f(options));
+
+        let (matches, filtered, disabled) = if self.searchable
+            && !query.is_empty()
+        {
+            let lowercase = query.to_lowercase();
+
+            let matches: Vec<usize> = options
+                .iter()
+                .enumerate()
+                .filter(|(_, option)| match &self.filter {
+                    Some(filter) => filter(option, query),
+                    None => self
+                        .label_of(option)
+                        .to_lowercase()
+                        .contains(&lowercase),
+                })
+                .map(|(i, _)| i)
+                .collect();
+
+            let filtered =
+                matches.iter().map(|&i| options[i].clone()).collect();
+
+            let disabled = disabled
+                .map(|disabled| matches.iter().map(|&i| disabled[i]).collect());
+
+            (Some(matches), filtered, disabled)
+        } else {
+            (None, Vec::new(), disabled)
+        };
+
+        let shown = if matches.is_some() {
+            filtered.as_slice()
+        } else {
+            options
+        };
+
+        let reasons = self.disabled_reason.as_ref().zip(disabled.as_ref()).map(
+            |(reason, disabled)| {
+                shown
+                    .iter()
+                    .zip(disabled)
+                    .map(|(option, &is_disabled)| {
+                        is_disabled.then(|| reason(option)).flatten()
+                    })
+                    .collect()
+            },
+        );
+
+        let headers = self.group.as_ref().map(|group| {
+            let mut headers = Vec::new();
+            let mut current = None;
+
+            for (i, option) in shown.iter().enumerate() {
+                let name = group(option);
+
+                if let Some(name) = name
+                    && current != Some(name)
+                {
+                    headers.push((i, name.to_owned()));
+                }
+
+                current = name;
+            }
+
+            headers
+        });
+
+        MenuOptions {
+            query: query.to_owned(),
+            matches,
+            filtered,
+            disabled,
+            reasons,
+            headers,
+        }
+    }
+
+    /// Opens the menu of the [`PickList`], hovering the selected option.
+    fn open(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let selected = self.selected.as_ref().map(Borrow::borrow);
+
+        state.is_open = true;
+        state.query.clear();
+        state.hovered_option = self
+            .options
+            .borrow()
+            .iter()
+            .position(|option| Some(option) == selected);
+
+        // Scroll the selected option into view
+        state.menu.reveal();
+
+        if let Some(on_open) = &self.on_open {
+            shell.publish(on_open.clone());
+        }
+    }
+
+    /// Returns the label shown for the given option.
+    fn label_of(&self, option: &T) -> String {
+        match &self.label {
+            Some(label) => label(option),
+            None => option.to_string(),
+        }
+    }
+
+    /// Hovers the next enabled option whose label starts with the typed
+    /// characters, if any.
+    ///
+    /// Typing the same character repeatedly cycles through the options
+    /// starting with it.
+    fn type_ahead(&self, state: &mut State<Renderer::Paragraph>) {
+        let options = self.options.borrow();
+        let disabled = self.disabled.as_ref().map(|f| f(options));
+
+        let buffer = state.type_ahead.to_lowercase();
+        let mut chars = buffer.chars();
+
+        let (prefix, skip) = match chars.next() {
+            Some(first) if chars.all(|c| c == first) => (first.to_string(), 1),
+            Some(_) => (buffer, 0),
+            None => return,
+        };
+
+        let start = state.hovered_option.map_or(0, |index| index + skip);
+
+        let next = (0..options.len())
+            .map(|offset| (start + offset) % options.len())
+            .find(|&index| {
+                let is_disabled =
+                    disabled.as_ref().is_some_and(|disabled| disabled[index]);
+
+                !is_disabled
+                    && self
+                        .label_of(&options[index])
+                        .to_lowercase()
+                        .starts_with(&prefix)
+            });
+
+        if let Some(index) = next {
+            state.hovered_option = Some(index);
+            state.menu.reveal();
+        }
+    }
 }
 
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -354,6 +696,24 @@ where
         tree::State::new(State::<Renderer::Paragraph>::new())
     }
 
+    fn children(&self) -> Vec<Tree> {
+        self.icons
+            .iter()
+            .map(|icon| icon.as_ref().map_or_else(Tree::empty, Tree::new))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children.resize_with(self.icons.len(), Tree::empty);
+
+        for (tree, icon) in tree.children.iter_mut().zip(&self.icons) {
+            match icon {
+                Some(icon) => tree.diff(icon),
+                None => *tree = Tree::empty(),
+            }
+        }
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -374,7 +734,15 @@ where
             self.text_size.unwrap_or_else(|| renderer.default_size());
         let options = self.options.borrow();
 
-        state.options.resize_with(options.len(), Default::default);
+        // Labels are only measured to fit the width of a shrinking field,
+        // which would be slow for long lists otherwise
+        let labels = if self.width == Length::Shrink {
+            options
+        } else {
+            &[]
+        };
+
+        state.options.resize_with(labels.len(), Default::default);
 
         let option_text = Text {
             content: "",
@@ -391,9 +759,8 @@ where
             wrapping: text::Wrapping::default(),
         };
 
-        for (option, paragraph) in options.iter().zip(state.options.iter_mut())
-        {
-            let label = option.to_string();
+        for (option, paragraph) in labels.iter().zip(state.options.iter_mut()) {
+            let label = self.label_of(option);
 
             let _ = paragraph.update(Text {
                 content: &label,
@@ -408,6 +775,44 @@ where
             });
         }
 
+        let line_height = self.text_line_height.to_absolute(text_size);
+        let height = f32::from(line_height) + self.padding.y();
+
+        let icon_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, f32::from(line_height)),
+        );
+
+        let icons: Vec<_> = self
+            .icons
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(icon, tree)| {
+                let Some(icon) = icon else {
+                    return layout::Node::default();
+                };
+
+                let node =
+                    icon.as_widget_mut().layout(tree, renderer, &icon_limits);
+                let icon_height = node.size().height;
+
+                node.move_to(Point::new(
+                    self.padding.left,
+                    (height - icon_height) / 2.0,
+                ))
+            })
+            .collect();
+
+        let icon_width = icons
+            .iter()
+            .fold(0.0, |width, icon| f32::max(width, icon.size().width));
+
+        state.label_offset = if icon_width > 0.0 {
+            icon_width + menu::ICON_SPACING
+        } else {
+            0.0
+        };
+
         let max_width = match self.width {
             Length::Shrink => {
                 let labels_width =
@@ -425,10 +830,20 @@ where
             _ => 0.0,
         };
 
+        let group_indent = if self.group.is_some() {
+            menu::GROUP_INDENT
+        } else {
+            0.0
+        };
+
         let size = {
             let intrinsic = Size::new(
-                max_width + text_size.0 + self.padding.left,
-                f32::from(self.text_line_height.to_absolute(text_size)),
+                max_width
+                    + state.label_offset
+                    + group_indent
+                    + text_size.0
+                    + self.padding.left,
+                f32::from(line_height),
             );
 
             limits
@@ -438,7 +853,26 @@ where
                 .expand(self.padding)
         };
 
-        layout::Node::new(size)
+        layout::Node::with_children(size, icons)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.custom(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut WidgetState::PickList {
+                is_open: state.is_open,
+            },
+        );
     }
 
     fn update(
@@ -454,9 +888,33 @@ where
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        // Detect focus changes from operations (e.g., Tab key)
+        if state.is_focused != state.was_focused {
+            if state.is_focused {
+                if let Some(on_focus) = &self.on_focus {
+                    shell.publish(on_focus.clone());
+                }
+            } else if let Some(on_blur) = &self.on_blur {
+                shell.publish(on_blur.clone());
+            }
+
+            state.was_focused = state.is_focused;
+        }
+
+        // Detect the menu closing on its own, after an option is selected
+        if state.was_open && !state.is_open {
+            if let Some(on_close) = &self.on_close {
+                shell.publish(on_close.clone());
+            }
+
+            state.was_open = false;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_over = cursor.is_over(layout.bounds());
+
                 if state.is_open {
                     // Event wasn't processed by overlay, so cursor was clicked either outside its
                     // bounds or on the drop-down, either way we close the overlay.
@@ -467,23 +925,105 @@ where
                     }
 
                     shell.capture_event();
-                } else if cursor.is_over(layout.bounds()) {
-                    let selected = self.selected.as_ref().map(Borrow::borrow);
+                } else if is_over {
+                    self.open(state, shell);
 
-                    state.is_open = true;
-                    state.hovered_option = self
-                        .options
-                        .borrow()
-                        .iter()
-                        .position(|option| Some(option) == selected);
+                    shell.capture_event();
+                }
 
-                    if let Some(on_open) = &self.on_open {
-                        shell.publish(on_open.clone());
+                if is_over != state.is_focused {
+                    let message = if is_over {
+                        &self.on_focus
+                    } else {
+                        &self.on_blur
+                    };
+
+                    if let Some(message) = message {
+                        shell.publish(message.clone());
                     }
+                }
+
+                state.is_focused = is_over;
+                state.was_focused = is_over;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if state.is_open => {
+                // Captured, so it does not reach a global handler that
+                // closes something else, e.g. the dialog around us
+                state.is_open = false;
+
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
 
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, text, ..
+            }) if state.is_open && self.searchable => {
+                let query = state.query.clone();
+
+                if let keyboard::Key::Named(keyboard::key::Named::Backspace) =
+                    key
+                {
+                    let _ = state.query.pop();
+                } else if let Some(text) = text
+                    && !state.keyboard_modifiers.command()
+                {
+                    state
+                        .query
+                        .extend(text.chars().filter(|c| !c.is_control()));
+                }
+
+                if state.query != query {
+                    state.hovered_option = None;
+
+                    shell.invalidate_layout();
                     shell.capture_event();
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                text: Some(text),
+                ..
+            }) if state.is_open
+                && !self.searchable
+                && !state.keyboard_modifiers.command()
+                && text.chars().any(|c| !c.is_control()) =>
+            {
+                let now = Instant::now();
+
+                if state
+                    .typed_at
+                    .is_none_or(|typed_at| now - typed_at > TYPE_AHEAD_TIMEOUT)
+                {
+                    state.type_ahead.clear();
+                }
+
+                state
+                    .type_ahead
+                    .extend(text.chars().filter(|c| !c.is_control()));
+                state.typed_at = Some(now);
+
+                self.type_ahead(state);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if matches!(
+                named,
+                keyboard::key::Named::Enter | keyboard::key::Named::Space
+            ) && state.is_focused
+                && !state.is_open =>
+            {
+                self.open(state, shell);
+
+                shell.capture_event();
+            }
             Event::Mouse(mouse::Event::WheelScrolled {
                 delta: mouse::ScrollDelta::Lines { y, .. },
             }) => {
@@ -532,12 +1072,14 @@ where
             _ => {}
         };
 
+        state.was_open = state.is_open;
+
         let status = {
             let is_hovered = cursor.is_over(layout.bounds());
 
             if state.is_open {
                 Status::Opened { is_hovered }
-            } else if is_hovered {
+            } else if is_hovered || state.is_focused {
                 Status::Hovered
             } else {
                 Status::Active
@@ -577,9 +1119,9 @@ where
         tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
-        _style: &renderer::Style,
+        defaults: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         let font = self.font.unwrap_or_else(|| renderer.default_font());
@@ -667,7 +1209,33 @@ where
             );
         }
 
-        let label = selected.map(ToString::to_string);
+        let query = (state.is_open && !state.query.is_empty())
+            .then(|| state.query.clone());
+
+        if query.is_none()
+            && let Some(selected) = selected
+            && let Some(index) = self
+                .options
+                .borrow()
+                .iter()
+                .position(|option| option == selected)
+            && let Some(Some(icon)) = self.icons.get(index)
+            && let Some(icon_layout) = layout.children().nth(index)
+        {
+            icon.as_widget().draw(
+                &tree.children[index],
+                renderer,
+                theme,
+                defaults,
+                icon_layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        let has_label = query.is_some() || selected.is_some();
+        let label =
+            query.or_else(|| selected.map(|selected| self.label_of(selected)));
 
         if let Some(label) = label.or_else(|| self.placeholder.clone()) {
             let text_size =
@@ -680,7 +1248,7 @@ where
                     line_height: self.text_line_height,
                     font,
                     bounds: Size::new(
-                        bounds.width - self.padding.x(),
+                        bounds.width - self.padding.x() - state.label_offset,
                         f32::from(self.text_line_height.to_absolute(text_size)),
                     ),
                     align_x: text::Alignment::Default,
@@ -688,8 +1256,11 @@ where
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
-                if selected.is_some() {
+                Point::new(
+                    bounds.x + self.padding.left + state.label_offset,
+                    bounds.center_y(),
+                ),
+                if has_label {
                     style.text_color
                 } else {
                     style.placeholder_color
@@ -713,28 +1284,83 @@ where
         if state.is_open {
             let bounds = layout.bounds();
 
+            // The options are filtered and decorated once per query, instead
+            // of every time the menu is laid out or drawn
+            if self
+                .menu_options
+                .as_ref()
+                .is_none_or(|menu_options| menu_options.query != state.query)
+            {
+                self.menu_options = Some(self.menu_options(&state.query));
+            }
+
+            let Some(menu_options) = &self.menu_options else {
+                return None;
+            };
+
             let on_select = &self.on_select;
 
-            let disabled =
-                self.disabled.as_ref().map(|f| f(self.options.borrow()));
+            let options = match &menu_options.matches {
+                Some(_) => menu_options.filtered.as_slice(),
+                None => self.options.borrow(),
+            };
+
+            let mut icons: Vec<_> = self
+                .icons
+                .iter_mut()
+                .zip(&mut tree.children)
+                .map(|(icon, tree)| icon.as_mut().map(|icon| (icon, tree)))
+                .collect();
+
+            if let Some(matches) = &menu_options.matches {
+                icons = matches
+                    .iter()
+                    .map(|&i| icons.get_mut(i).and_then(Option::take))
+                    .collect();
+            }
 
             let mut menu = Menu::new(
                 &mut state.menu,
-                self.options.borrow(),
+                options,
                 &mut state.hovered_option,
                 |option| {
                     state.is_open = false;
 
                     (on_select)(option)
                 },
-                disabled,
+                menu_options.disabled.clone(),
                 None,
                 &self.menu_class,
             )
             .width(bounds.width)
             .padding(self.padding)
             .font(font)
-            .text_shaping(self.text_shaping);
+            .text_shaping(self.text_shaping)
+            .scrollbar_embedded(self.menu_scrollbar_always_visible);
+
+            if let Some(style) = &self.menu_scrollbar {
+                menu = menu.scrollbar_style(style);
+            }
+
+            if let Some(label) = &self.label {
+                menu = menu.label(label.as_ref());
+            }
+
+            menu = menu.icons(icons);
+
+            if let Some(reasons) = &menu_options.reasons {
+                menu = menu.disabled_reasons(reasons.as_slice());
+            }
+
+            if let Some(rows) = self.max_visible {
+                menu = menu.max_visible(rows);
+            }
+
+            menu = menu.placement(self.menu_placement);
+
+            if let Some(headers) = &menu_options.headers {
+                menu = menu.headers(headers.as_slice());
+            }
 
             if let Some(text_size) = self.text_size {
                 menu = menu.text_size(text_size);
@@ -770,12 +1396,31 @@ where
     }
 }
 
+/// The options shown by the [`Menu`] of a [`PickList`] for a query.
+struct MenuOptions<T> {
+    query: String,
+    /// The indices of the options matching the query, when searching.
+    matches: Option<Vec<usize>>,
+    /// The options matching the query, when searching.
+    filtered: Vec<T>,
+    disabled: Option<Vec<bool>>,
+    reasons: Option<Vec<Option<String>>>,
+    headers: Option<Vec<(usize, String)>>,
+}
+
 #[derive(Debug)]
 struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
+    was_focused: bool,
+    was_open: bool,
+    query: String,
+    type_ahead: String,
+    typed_at: Option<Instant>,
     hovered_option: Option<usize>,
+    label_offset: f32,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
 }
@@ -787,7 +1432,14 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: false,
+            was_focused: false,
+            was_open: false,
+            query: String::new(),
+            type_ahead: String::new(),
+            typed_at: None,
             hovered_option: Option::default(),
+            label_offset: 0.0,
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
         }
@@ -800,6 +1452,24 @@ impl<P: text::Paragraph> Default for State<P> {
     }
 }
 
+/// The time after which typing in the open menu of a [`PickList`] starts a
+/// new type-ahead search instead of extending the current one.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl<P: text::Paragraph> Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -938,3 +1608,81 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::key;
+    use crate::test::Harness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(&'static str),
+        Opened,
+        Closed,
+    }
+
+    const OPTIONS: [&str; 3] = ["Apple", "Banana", "Cherry"];
+
+    fn harness() -> Harness<'static, Message> {
+        let pick_list: PickList<'_, _, _, &str, _, crate::Theme, ()> =
+            PickList::new(&OPTIONS[..], None, Message::Selected)
+                .width(100)
+                .on_open(Message::Opened)
+                .on_close(Message::Closed);
+
+        Harness::new(pick_list, Size::new(200.0, 200.0))
+    }
+
+    #[test]
+    fn open_and_close_are_published_once() {
+        let mut harness = harness();
+
+        harness.click(Point::new(50.0, 10.0));
+        assert_eq!(harness.messages(), [Message::Opened]);
+
+        harness.tap_key(key::Named::Escape);
+        assert_eq!(harness.messages(), [Message::Closed]);
+
+        harness.tap_key(key::Named::Escape);
+        assert_eq!(harness.messages(), []);
+
+        harness.tap_key(key::Named::Enter);
+        assert_eq!(harness.messages(), [Message::Opened]);
+
+        harness.click(Point::new(150.0, 150.0));
+        assert_eq!(harness.messages(), [Message::Closed]);
+
+        harness.click(Point::new(150.0, 150.0));
+        assert_eq!(harness.messages(), []);
+    }
+
+    #[test]
+    fn escape_closes_the_menu_and_is_captured() {
+        let mut harness = harness();
+
+        let escape = || {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key::Named::Escape.into(),
+                modified_key: key::Named::Escape.into(),
+                physical_key: key::Physical::Unidentified(
+                    key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text: None,
+                repeat: false,
+            })
+        };
+
+        harness.click(Point::new(50.0, 10.0));
+        let _ = harness.messages();
+
+        assert!(harness.event(escape()));
+        assert_eq!(harness.messages(), [Message::Closed]);
+
+        // Once closed, Escape is left to the rest of the application
+        assert!(!harness.event(escape()));
+        assert_eq!(harness.messages(), []);
+    }
+}
@@ -26,21 +26,38 @@
 //! ```
 
 use crate::core::alignment::{self, Alignment};
+use crate::core::border;
+use crate::core::keyboard;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::time::Instant;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::operation::Focusable;
 use crate::core::widget::{Operation, Tree, tree};
 use crate::core::{
     Animation, Background, Border, Clipboard, Color, Element, Event, Length,
     Padding, Pixels, Point, Rectangle, Shell, Size, Transformation, Vector,
     Widget,
 };
+use crate::widget::operation::ContainsFocus;
+use crate::widget::overlay::drag_preview::DragPreview;
 
-use super::drag::DragEvent;
+use super::drag::{
+    self, DragEvent, DragTransfer, DropPreview, DropZones, Session, TargetId,
+};
+
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// The offset between the stacked cards drawn behind a dragged selection.
+const STACK_OFFSET: f32 = 4.0;
+
+/// The width of the area along the edges of the viewport where dragging an
+/// item scrolls.
+const DRAG_SCROLL_MARGIN: f32 = 40.0;
 
-const DRAG_DEADBAND_DISTANCE: f32 = 5.0;
+/// The maximum distance scrolled per frame while dragging near an edge.
+const DRAG_SCROLL_SPEED: f32 = 12.0;
 
 /// A container that distributes its contents horizontally.
 ///
@@ -75,9 +92,35 @@ where
     height: Length,
     align: Alignment,
     clip: bool,
-    deadband_zone: f32,
+    drag_threshold: f32,
+    drop_preview: DropPreview,
+    drop_zones: DropZones,
+    axis_lock: bool,
+    dim_others: Option<f32>,
+    reorder_after_animation: bool,
+    reorder_animation: Option<Duration>,
+    insertion_duration: Option<Duration>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
+    on_drop_with:
+        Option<Box<dyn Fn(DragEvent, keyboard::Modifiers) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    on_drag_multi: Option<OnDragMultiFn<'a, Message>>,
+    selection: Vec<usize>,
+    on_drag_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    drag_scroll_margin: f32,
+    drop_targets: Vec<(usize, TargetId)>,
+    on_drop_target: Option<Box<dyn Fn(usize, TargetId) -> Message + 'a>>,
+    on_drag_start: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_drag_over: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_drag_end: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_drag_cancel: Option<Box<dyn Fn() -> Message + 'a>>,
+    draggable: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+    keyboard_reorder: bool,
+    drag_group: Option<String>,
+    on_drag_out: Option<Box<dyn Fn(DragTransfer) -> Message + 'a>>,
+    drag_preview: Option<DragPreviewFn<'a, Message, Theme, Renderer>>,
     class: Theme::Class<'a>,
 }
 
@@ -122,10 +165,35 @@ where
             height: Length::Shrink,
             align: Alignment::Start,
             clip: false,
-            deadband_zone: DRAG_DEADBAND_DISTANCE,
+            drag_threshold: DRAG_THRESHOLD,
+            drop_preview: DropPreview::default(),
+            drop_zones: DropZones::default(),
+            axis_lock: false,
+            dim_others: None,
+            reorder_after_animation: false,
+            reorder_animation: None,
+            insertion_duration: None,
             children,
             class: Theme::default(),
             on_drag: None,
+            on_drop_with: None,
+            on_drop: None,
+            on_reorder: None,
+            on_drag_multi: None,
+            selection: Vec::new(),
+            on_drag_scroll: None,
+            drag_scroll_margin: DRAG_SCROLL_MARGIN,
+            drop_targets: Vec::new(),
+            on_drop_target: None,
+            on_drag_start: None,
+            on_drag_over: None,
+            on_drag_end: None,
+            on_drag_cancel: None,
+            draggable: None,
+            keyboard_reorder: false,
+            drag_group: None,
+            on_drag_out: None,
+            drag_preview: None,
         }
     }
 
@@ -145,104 +213,1258 @@ where
         self
     }
 
-    /// Sets the width of the [`Row`].
-    pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
-        self
-    }
+    /// Sets the width of the [`Row`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Row`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Row`] .
+    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
+        self.align = Alignment::from(align.into());
+        self
+    }
+
+    /// Sets whether the contents of the [`Row`] should be clipped on
+    /// overflow.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Sets the drag threshold of the [`Row`].
+    ///
+    /// This is the distance in logical pixels that the cursor must move
+    /// away from where an item was pressed before a drag operation begins.
+    /// Releasing before that is a plain click, which leaves the items in
+    /// place. Default is 5.0 pixels.
+    ///
+    /// A larger threshold keeps small jitters, like those of a finger on
+    /// a touch screen, from starting a drag.
+    pub fn drag_threshold(mut self, drag_threshold: f32) -> Self {
+        self.drag_threshold = drag_threshold;
+        self
+    }
+
+    /// Sets the drag deadband zone of the [`Row`].
+    ///
+    /// This is an alias of [`Row::drag_threshold`].
+    pub fn deadband_zone(self, deadband_zone: f32) -> Self {
+        self.drag_threshold(deadband_zone)
+    }
+
+    /// Sets how the [`Row`] shows where a dragged item will be dropped.
+    ///
+    /// By default, the other items move apart to make room for it. The
+    /// preview only applies to mouse drags; items grabbed with the keyboard
+    /// always make room.
+    pub fn drop_preview(mut self, drop_preview: DropPreview) -> Self {
+        self.drop_preview = drop_preview;
+        self
+    }
+
+    /// Sets whether a dragged item of the [`Row`] only follows the cursor
+    /// along the horizontal axis.
+    ///
+    /// Either way, the item keeps the point where it was grabbed under the
+    /// cursor instead of jumping to center on it; when locked, it stays
+    /// aligned with the [`Row`] and only that point's horizontal position
+    /// follows the cursor.
+    pub fn axis_lock(mut self, axis_lock: bool) -> Self {
+        self.axis_lock = axis_lock;
+        self
+    }
+
+    /// Dims the items of the [`Row`] while one is dragged with the mouse,
+    /// except for the dragged item and the two items around its drop
+    /// position.
+    ///
+    /// Widgets cannot be drawn with a different opacity, so the dimmed items
+    /// are covered with the [`Style::dimmed_item_overlay`] color instead,
+    /// with an alpha of `1.0 - alpha`. When that color matches the
+    /// background, `alpha` is effectively the opacity of the dimmed items.
+    ///
+    /// This draws an extra quad over most items on every frame of a drag,
+    /// which can add up in long lists. By default, no items are dimmed.
+    pub fn dim_others(mut self, alpha: f32) -> Self {
+        self.dim_others = Some(alpha.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets how the [`Row`] picks where a dragged item will be dropped,
+    /// from the position of the cursor.
+    ///
+    /// Only the horizontal position of the cursor matters, so this is unaffected
+    /// by [`axis_lock`](Self::axis_lock). With [`DropZones::HalfItem`], the
+    /// cursor in the spacing between two items drops the item between them.
+    pub fn drop_zones(mut self, drop_zones: DropZones) -> Self {
+        self.drop_zones = drop_zones;
+        self
+    }
+
+    /// Sets whether a dropped item settles into its new place before the
+    /// [`DragEvent::Dropped`] event is emitted.
+    ///
+    /// By default, the event is emitted as soon as the item is dropped, and
+    /// the application reorders its items right away. When enabled, the
+    /// dropped item first slides into its new place, with the other items
+    /// making room for it; then the event is emitted, so the application
+    /// reorders its items just as they look reordered.
+    ///
+    /// Pressing the [`Row`] again while an item settles finishes it at
+    /// once: the event is emitted right away, and that press does not pick
+    /// an item, since the items are about to move.
+    pub fn reorder_after_animation(
+        mut self,
+        reorder_after_animation: bool,
+    ) -> Self {
+        self.reorder_after_animation = reorder_after_animation;
+        self
+    }
+
+    /// Animates the children of the [`Row`] into their new places after a
+    /// drop, over the given duration.
+    ///
+    /// By default, the items are laid out in their new order at once when
+    /// dropped. With an animation, each item slides from where it was drawn
+    /// when the drop happened, including the dropped item itself, to its new
+    /// slot. A zero duration keeps the default behavior.
+    ///
+    /// The new order is the one reported by the drop, so the application is
+    /// expected to apply it right away.
+    pub fn reorder_animation(mut self, duration: Duration) -> Self {
+        self.reorder_animation = (!duration.is_zero()).then_some(duration);
+        self
+    }
+
+    /// Animates the children added to the [`Row`], scaling them in over the
+    /// given duration.
+    ///
+    /// Children are matched by position, like the rest of the widget tree, so
+    /// the slots added at the end of the [`Row`] are the ones animated.
+    ///
+    /// Removed children cannot be animated out, since the [`Row`] does not
+    /// own them past the view that created them.
+    ///
+    /// Insertions are independent of the reorder animation: items shifted by
+    /// a drop keep scaling in while they slide into place, while an item that
+    /// is picked up is drawn at full size.
+    pub fn animate_insertions(mut self, duration: Duration) -> Self {
+        self.insertion_duration = Some(duration);
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Row`].
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let child = child.into();
+        let child_size = child.as_widget().size_hint();
+
+        if !child_size.is_void() {
+            self.width = self.width.enclose(child_size.width);
+            self.height = self.height.enclose(child_size.height);
+            self.children.push(child);
+        }
+
+        self
+    }
+
+    /// Adds an element to the [`Row`], if `Some`.
+    pub fn push_maybe(
+        self,
+        child: Option<impl Into<Element<'a, Message, Theme, Renderer>>>,
+    ) -> Self {
+        if let Some(child) = child {
+            self.push(child)
+        } else {
+            self
+        }
+    }
+
+    /// Sets the style of the [`Row`], used while dragging its items.
+    ///
+    /// See [`Style`] for the parts of the drag each field styles.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Row`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Extends the [`Row`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    /// Sets a handler for drag events.
+    ///
+    /// When set, items in the [`Row`] can be dragged and reordered.
+    /// The handler receives a [`DragEvent`] describing what happened.
+    ///
+    /// Only the horizontal position of the cursor decides where a dragged item
+    /// is dropped: its projection onto the axis of the [`Row`]. Any
+    /// vertical movement, even past the bounds of the [`Row`], does not
+    /// change the target, so diagonal drags track cleanly. To also keep
+    /// the dragged item itself on that axis, see [`Row::axis_lock`].
+    ///
+    /// # Keyboard
+    ///
+    /// A draggable [`Row`] is also focusable, which lets items be
+    /// reordered without a pointer:
+    ///
+    /// - <kbd>Left</kbd> / <kbd>Right</kbd> select the previous or next
+    ///   item.
+    /// - <kbd>Space</kbd> grabs the selected item ([`DragEvent::Picked`]).
+    /// - While grabbed, <kbd>Left</kbd> / <kbd>Right</kbd> move the item
+    ///   one position at a time ([`Row::on_drag_over`]).
+    /// - <kbd>Space</kbd> or <kbd>Enter</kbd> drops the item
+    ///   ([`DragEvent::Dropped`]); <kbd>Escape</kbd> cancels the grab
+    ///   ([`DragEvent::Canceled`]).
+    ///
+    /// Children receive key presses first. If a child captures a key (e.g. a
+    /// focused button activating on <kbd>Space</kbd>), the [`Row`] ignores it.
+    pub fn on_drag(
+        mut self,
+        on_drag: impl Fn(DragEvent) -> Message + 'a,
+    ) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Sets a handler for drops that also receives the
+    /// [`keyboard::Modifiers`] held when the item was released.
+    ///
+    /// This lets applications implement semantics like "drop with
+    /// <kbd>Ctrl</kbd> to copy, plain drop to move". The [`Row`] only
+    /// reports the intent; actually copying the item is up to the
+    /// application.
+    ///
+    /// The handler is only called with [`DragEvent::Dropped`]. When set, it
+    /// replaces [`Row::on_drag`] for drops, while picks and cancellations
+    /// are still reported through [`Row::on_drag`].
+    pub fn on_drop_with(
+        mut self,
+        on_drop_with: impl Fn(DragEvent, keyboard::Modifiers) -> Message + 'a,
+    ) -> Self {
+        self.on_drop_with = Some(Box::new(on_drop_with));
+        self
+    }
+
+    /// Sets the message that will be produced when an item of the [`Row`]
+    /// is dropped.
+    ///
+    /// The handler receives the current index of the dropped item and the
+    /// index it was dropped at, like [`DragEvent::Dropped`]. Unlike
+    /// [`Row::on_drag`], it is never called for picks and cancellations.
+    ///
+    /// It is produced for every drop, on top of the [`DragEvent::Dropped`]
+    /// of [`Row::on_drag`] or [`Row::on_drop_with`] if either is set too.
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message that will be produced with the new order of the
+    /// items of the [`Row`] when an item is dropped.
+    ///
+    /// The handler receives a permutation of the indices of the children:
+    /// the `i`-th element is the current index of the item that ends up at
+    /// position `i`. It always contains every index from `0` to the number
+    /// of children exactly once, so it can be applied to a model kept in
+    /// the same order as the children with a single pass:
+    ///
+    /// ```
+    /// let items = vec!["a", "b", "c", "d"];
+    /// let order = vec![0, 2, 3, 1]; // "b" was dropped at the end
+    ///
+    /// let items: Vec<_> = order.iter().map(|&i| items[i]).collect();
+    ///
+    /// assert_eq!(items, ["a", "c", "d", "b"]);
+    /// ```
+    ///
+    /// It is produced for every drop, on top of the [`DragEvent::Dropped`]
+    /// of [`Row::on_drag`] if that is set too, which keeps working as before.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(Vec<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the message that will be produced to scroll horizontally while an
+    /// item is dragged near the left or right edge of the viewport.
+    ///
+    /// A [`Row`] cannot scroll its ancestors by itself. Instead, while the
+    /// cursor stays within the edge area, it produces the distance to scroll
+    /// by on every frame: negative towards the left and positive towards the
+    /// right. Nothing is produced unless the [`Row`] overflows the viewport
+    /// on that side.
+    ///
+    /// Map it to [`scroll_by`] on the surrounding horizontal scrollable:
+    ///
+    /// ```no_run
+    /// # mod iced { pub mod widget { pub use iced_widget::*; pub use iced_runtime::widget::operation; } pub use iced_runtime::Task; }
+    /// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+    /// use iced::Task;
+    /// use iced::widget::{operation, scrollable, Id};
+    /// use iced::widget::scrollable::{AbsoluteOffset, Direction, Scrollbar};
+    /// use sweeten::widget::drag::DragEvent;
+    /// use sweeten::widget::row;
+    ///
+    /// const ITEMS: Id = Id::new("items");
+    ///
+    /// #[derive(Clone)]
+    /// enum Message {
+    ///     Reorder(DragEvent),
+    ///     Scroll(f32),
+    /// }
+    ///
+    /// fn view(items: &[String]) -> Element<'_, Message> {
+    ///     scrollable(
+    ///         row(items.iter().map(|s| s.as_str().into()))
+    ///             .on_drag(Message::Reorder)
+    ///             .on_drag_scroll(Message::Scroll),
+    ///     )
+    ///     .direction(Direction::Horizontal(Scrollbar::default()))
+    ///     .id(ITEMS)
+    ///     .into()
+    /// }
+    ///
+    /// fn update(message: Message) -> Task<Message> {
+    ///     match message {
+    ///         Message::Scroll(x) => {
+    ///             operation::scroll_by(ITEMS, AbsoluteOffset { x, y: 0.0 })
+    ///         }
+    ///         Message::Reorder(_) => Task::none(),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Dragging keeps working when the cursor leaves the scrollable, and
+    /// items are always dropped where they are shown, however far the
+    /// [`Row`] is scrolled.
+    ///
+    /// [`scroll_by`]: https://docs.iced.rs/iced/widget/operation/fn.scroll_by.html
+    pub fn on_drag_scroll(
+        mut self,
+        on_drag_scroll: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        self.on_drag_scroll = Some(Box::new(on_drag_scroll));
+        self
+    }
+
+    /// Sets the width of the area along the left and right edges of the
+    /// viewport where dragging an item scrolls. Default is 40.0 pixels.
+    ///
+    /// A margin of zero disables scrolling while dragging.
+    ///
+    /// See [`Row::on_drag_scroll`].
+    pub fn drag_scroll_margin(mut self, margin: impl Into<Pixels>) -> Self {
+        self.drag_scroll_margin = margin.into().0;
+        self
+    }
+
+    /// Turns the child at `index` into a drop target with the given
+    /// [`TargetId`].
+    ///
+    /// A drop target is not reorderable: it cannot be picked up, and the
+    /// other items do not make room for it. Instead, while a dragged item
+    /// is over it, the target is highlighted, and dropping the item there
+    /// produces the message of [`Row::on_drop_target`] instead of a
+    /// [`DragEvent::Dropped`] reorder. This is useful for things like a
+    /// "trash" slot at the end of a list.
+    ///
+    /// Drop targets take precedence over the insertion zones of the
+    /// [`Row`]: dropping anywhere else still reorders the items as usual.
+    /// They only apply to mouse drags; items grabbed with the keyboard move
+    /// past them.
+    pub fn drop_target(mut self, index: usize, id: TargetId) -> Self {
+        self.drop_targets.push((index, id));
+        self
+    }
+
+    /// Sets the message that will be produced when a dragged item is dropped
+    /// onto a drop target of the [`Row`].
+    ///
+    /// The handler receives the index of the dropped item and the
+    /// [`TargetId`] of the target. Without it, drop targets are ignored
+    /// while dragging.
+    ///
+    /// See [`Row::drop_target`].
+    pub fn on_drop_target(
+        mut self,
+        on_drop_target: impl Fn(usize, TargetId) -> Message + 'a,
+    ) -> Self {
+        self.on_drop_target = Some(Box::new(on_drop_target));
+        self
+    }
+
+    /// Sets the message that will be produced when an item of the [`Row`]
+    /// starts being dragged.
+    ///
+    /// The handler receives the index of the grabbed item. It is called
+    /// once the cursor moves past the [`drag_threshold`](Row::drag_threshold)
+    /// of a pressed item, or when an item is grabbed with the keyboard.
+    ///
+    /// This is useful to react to the whole drag at once, like dimming the
+    /// other items, without matching on every [`DragEvent`].
+    pub fn on_drag_start(
+        mut self,
+        on_drag_start: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_drag_start = Some(Box::new(on_drag_start));
+        self
+    }
+
+    /// Sets the message that will be produced when an item grabbed with the
+    /// keyboard moves over a new position of the [`Row`].
+    ///
+    /// The handler receives the index of the grabbed item and the index of
+    /// the position it would be dropped at, e.g. to announce it to screen
+    /// readers. The items are only reordered once the grabbed item is
+    /// dropped, through [`Row::on_drag`] or [`Row::on_reorder`].
+    pub fn on_drag_over(
+        mut self,
+        on_drag_over: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_drag_over = Some(Box::new(on_drag_over));
+        self
+    }
+
+    /// Sets the message that will be produced when a drag of the [`Row`]
+    /// ends, whether the item was dropped or the drag was canceled.
+    ///
+    /// It is produced after the drop itself, so the application has already
+    /// seen the new order of its items; e.g. to persist it.
+    pub fn on_drag_end(mut self, on_drag_end: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_drag_end = Some(Box::new(move || on_drag_end.clone()));
+        self
+    }
+
+    /// Sets the message that will be produced when a drag of the [`Row`] is
+    /// canceled instead of dropped.
+    ///
+    /// A drag is canceled by pressing <kbd>Escape</kbd> while dragging or
+    /// grabbing an item, or when the cursor is lost. The items go back to
+    /// their original order and no [`DragEvent::Dropped`] is produced.
+    pub fn on_drag_cancel(mut self, on_drag_cancel: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_drag_cancel = Some(Box::new(move || on_drag_cancel.clone()));
+        self
+    }
+
+    /// Sets a predicate deciding which items of the [`Row`] can be dragged.
+    ///
+    /// The predicate receives the index of an item when it is pressed, or
+    /// grabbed with the keyboard. If it returns `false`, the drag never
+    /// starts and the event is left to the item, like in a [`Row`] without
+    /// drag handlers. By default, every item can be dragged.
+    ///
+    /// Items that cannot be dragged still take part in the reordering of
+    /// the others: dropping another item before them shifts them, and the
+    /// indices reported by [`Row::on_drag`] are always positions among all
+    /// the children, locked or not. Keeping locked items in place (e.g. by
+    /// clamping the `target_index` of a [`DragEvent::Dropped`]) is up to
+    /// the application.
+    pub fn draggable(mut self, draggable: impl Fn(usize) -> bool + 'a) -> Self {
+        self.draggable = Some(Box::new(draggable));
+        self
+    }
+
+    /// Sets whether the item containing the focused widget can be moved with
+    /// the keyboard.
+    ///
+    /// When enabled, pressing <kbd>Alt</kbd> + <kbd>Left</kbd> or
+    /// <kbd>Alt</kbd> + <kbd>Right</kbd> while a widget inside an item is
+    /// focused (e.g. a button) moves that item one position back or forward,
+    /// producing a [`DragEvent::Dropped`] through [`Row::on_drag`] at once.
+    /// Keys without <kbd>Alt</kbd> are left alone. Disabled by default.
+    ///
+    /// The state of the moved item, including its focus, moves along with
+    /// it, so the application is expected to apply every reorder it
+    /// receives.
+    pub fn keyboard_reorder(mut self, keyboard_reorder: bool) -> Self {
+        self.keyboard_reorder = keyboard_reorder;
+        self
+    }
+
+    /// Sets the drag group of the [`Row`], naming it in drags between
+    /// containers.
+    ///
+    /// Every [`Row`] and [`Column`] with a drag group takes part in a shared
+    /// drag session: while an item of a draggable container with
+    /// [`Row::on_drag_out`] is dragged over another one with a group, the
+    /// other container draws where the item would land, and dropping it
+    /// there produces a [`DragTransfer`] naming both groups. The source
+    /// items move back into place meanwhile, since the item is leaving.
+    ///
+    /// The session is shared by the whole process rather than by a window:
+    /// only one item can be dragged between containers at a time, and the
+    /// containers with a drag group in other windows take part in it too.
+    ///
+    /// The dragged item is still drawn by its own container, in tree
+    /// order, so containers drawn after it may cover it. An overlay-based
+    /// preview does not have this problem: overlays are drawn above the
+    /// whole tree and positioned in absolute coordinates, so the preview
+    /// can keep following the cursor past the bounds of its container.
+    ///
+    /// [`Row`]: super::Row
+    /// [`Column`]: super::Column
+    pub fn drag_group(mut self, group: impl Into<String>) -> Self {
+        self.drag_group = Some(group.into());
+        self
+    }
+
+    /// Sets the message that will be produced when an item of the [`Row`] is
+    /// dropped onto another container with a drag group.
+    ///
+    /// The [`Row`] needs a drag group itself; see [`Row::drag_group`]. The
+    /// drop is not reported through [`Row::on_drag`]; moving the item from
+    /// one list to the other is up to the application.
+    pub fn on_drag_out(
+        mut self,
+        on_drag_out: impl Fn(DragTransfer) -> Message + 'a,
+    ) -> Self {
+        self.on_drag_out = Some(Box::new(on_drag_out));
+        self
+    }
+
+    /// Sets the preview drawn under the cursor while an item of the [`Row`]
+    /// is dragged with the pointer.
+    ///
+    /// The function receives the index of the dragged item and builds the
+    /// preview, which is drawn as an overlay: above every other widget, even
+    /// past the bounds of the [`Row`], and kept within the window. The
+    /// preview does not get any events. Meanwhile, the slot of the item is
+    /// left empty. Without a preview, the item itself moves with the cursor.
+    ///
+    /// Since the preview is built from scratch on every frame, it does not
+    /// keep any state; make it semi-transparent with its own style.
+    pub fn drag_preview(
+        mut self,
+        drag_preview: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.drag_preview = Some(Box::new(drag_preview));
+        self
+    }
+
+    /// Sets the indices of the selected items of the [`Row`], which are
+    /// dragged together.
+    ///
+    /// Dragging any of the selected items moves the whole selection, whether
+    /// it is contiguous or not: stacked cards are drawn behind the dragged
+    /// item and the rest of the selection is highlighted. On drop, the items
+    /// are reported through [`Row::on_drag_multi`] and [`Row::on_reorder`]
+    /// instead of a [`DragEvent::Dropped`].
+    ///
+    /// Dragging an item that is not selected, or a selection of a single
+    /// item, is a regular drag of that item alone. The selection is left
+    /// unchanged either way; keeping track of it (e.g. on
+    /// <kbd>Ctrl</kbd>/<kbd>Shift</kbd> clicks) is up to the application.
+    pub fn selected(mut self, selection: impl Into<Vec<usize>>) -> Self {
+        self.selection = selection.into();
+        self
+    }
+
+    /// Sets the message that will be produced when a selection of several
+    /// items of the [`Row`] is dropped.
+    ///
+    /// The handler receives the indices of the moved items, in ascending
+    /// order, and the slot they are inserted at: a position among the
+    /// current items, from `0` to their number. The moved items end up
+    /// together, in their current order, right before the item at that
+    /// slot (or at the end):
+    ///
+    /// ```
+    /// let items = vec!["a", "b", "c", "d", "e"];
+    /// let (moved, slot) = (vec![0, 2], 4); // "a" and "c" dropped before "e"
+    ///
+    /// let (before, after): (Vec<_>, Vec<_>) = (0..items.len())
+    ///     .filter(|i| !moved.contains(i))
+    ///     .partition(|i| *i < slot);
+    ///
+    /// let items: Vec<_> = before
+    ///     .iter()
+    ///     .chain(&moved)
+    ///     .chain(&after)
+    ///     .map(|&i| items[i])
+    ///     .collect();
+    ///
+    /// assert_eq!(items, ["b", "d", "a", "c", "e"]);
+    /// ```
+    ///
+    /// See [`Row::selected`].
+    pub fn on_drag_multi(
+        mut self,
+        on_drag_multi: impl Fn(Vec<usize>, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_drag_multi = Some(Box::new(on_drag_multi));
+        self
+    }
+
+    /// Returns the items moved along with the item at `index`, in ascending
+    /// order, if it is part of a selection of several items.
+    fn moved_items(&self, index: usize) -> Option<Vec<usize>> {
+        let mut moved: Vec<_> = self
+            .selection
+            .iter()
+            .copied()
+            .filter(|i| *i < self.children.len())
+            .collect();
+
+        moved.sort_unstable();
+        moved.dedup();
+
+        (moved.len() > 1 && moved.contains(&index)).then_some(moved)
+    }
+
+    /// Returns whether the items of the [`Row`] can be dragged.
+    fn is_draggable(&self) -> bool {
+        self.on_drag.is_some()
+            || self.on_drop_with.is_some()
+            || self.on_drop.is_some()
+            || self.on_reorder.is_some()
+            || self.on_drag_multi.is_some()
+    }
+
+    /// Returns whether the child at `index` can be picked up.
+    ///
+    /// Drop targets cannot be picked up.
+    fn can_pick(&self, index: usize) -> bool {
+        !self.drop_targets.iter().any(|(target, _)| *target == index)
+            && self
+                .draggable
+                .as_ref()
+                .is_none_or(|draggable| draggable(index))
+    }
+
+    /// Returns the drop target under the cursor, if any.
+    fn drop_target_at(
+        &self,
+        cursor_position: Point,
+        layout: Layout<'_>,
+    ) -> Option<(usize, TargetId)> {
+        self.on_drop_target.as_ref()?;
+
+        let index = self.compute_target_index(cursor_position, layout);
+
+        self.drop_targets
+            .iter()
+            .find(|(target, _)| *target == index)
+            .copied()
+    }
+
+    /// Publishes the start of the drag of the item at `index`.
+    fn publish_pick(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_drag) = &self.on_drag {
+            shell.publish(on_drag(DragEvent::Picked { index }));
+        }
+
+        if let Some(on_drag_start) = &self.on_drag_start {
+            shell.publish(on_drag_start(index));
+        }
+    }
+
+    /// Publishes the cancellation of the drag of the item at `index`.
+    fn publish_cancel(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_drag) = &self.on_drag {
+            shell.publish(on_drag(DragEvent::Canceled { index }));
+        }
+
+        if let Some(on_drag_cancel) = &self.on_drag_cancel {
+            shell.publish(on_drag_cancel());
+        }
+
+        self.publish_drag_end(shell);
+    }
+
+    /// Publishes the end of the current drag, if anyone is listening.
+    fn publish_drag_end(&self, shell: &mut Shell<'_, Message>) {
+        if let Some(on_drag_end) = &self.on_drag_end {
+            shell.publish(on_drag_end());
+        }
+    }
+
+    /// Returns the new order of the items after dropping the item at `index`
+    /// onto `target_index`, along with the rest of its selection.
+    fn new_order(&self, index: usize, target_index: usize) -> Vec<usize> {
+        let moved = self.moved_items(index).unwrap_or_else(|| vec![index]);
+
+        drag::reorder(
+            self.children.len(),
+            &moved,
+            drag::drop_slot(index, target_index),
+        )
+    }
+
+    /// Resets the offsets of the items for their new `order`, animating them
+    /// from where they are drawn if there is a [`reorder_animation`].
+    ///
+    /// The `dragged` item, if any, is drawn at the given offset instead of its
+    /// own. While idle, only offsets that are animating are drawn.
+    ///
+    /// [`reorder_animation`]: Row::reorder_animation
+    fn reflow(
+        &self,
+        layout: Layout<'_>,
+        order: &[usize],
+        dragged: Option<(usize, f32)>,
+        is_idle: bool,
+        animations: &mut ItemAnimations,
+    ) {
+        let Some(duration) = self.reorder_animation else {
+            animations.zero();
+            return;
+        };
+
+        let now = Instant::now();
+        let bounds: Vec<_> =
+            layout.children().map(|child| child.bounds()).collect();
+
+        let Some(first) = bounds.first() else {
+            return;
+        };
+
+        animations.with_capacity(self.children.len());
+
+        let drawn: Vec<_> = bounds
+            .iter()
+            .zip(&animations.offsets)
+            .enumerate()
+            .map(|(i, (bounds, offset))| {
+                let offset = match dragged {
+                    Some((index, offset)) if index == i => offset,
+                    _ if is_idle && !offset.is_animating(now) => 0.0,
+                    _ => offset.interpolate_with(|v| v, now),
+                };
+
+                bounds.x + offset
+            })
+            .collect();
+
+        let mut x = first.x;
+
+        for (i, &j) in order.iter().enumerate() {
+            animations.offsets[i] =
+                Animation::new(drawn[j] - x).duration(duration).go(0.0, now);
+
+            x += bounds[j].width + self.spacing;
+        }
+    }
+
+    /// Publishes the drop of the item at `index` onto `target_index`.
+    fn publish_drop(
+        &self,
+        index: usize,
+        target_index: usize,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let slot = drag::drop_slot(index, target_index);
+
+        if let Some(moved) = self.moved_items(index) {
+            if let Some(on_reorder) = &self.on_reorder {
+                shell.publish(on_reorder(self.new_order(index, target_index)));
+                shell.capture_event();
+            }
+
+            if let Some(on_drag_multi) = &self.on_drag_multi {
+                shell.publish(on_drag_multi(moved, slot));
+                shell.capture_event();
+            }
+
+            self.publish_drag_end(shell);
+            return;
+        }
+
+        let event = DragEvent::Dropped {
+            index,
+            target_index,
+        };
+
+        if let Some(on_drop_with) = &self.on_drop_with {
+            shell.publish(on_drop_with(event, modifiers));
+            shell.capture_event();
+        } else if let Some(on_drag) = &self.on_drag {
+            shell.publish(on_drag(event));
+            shell.capture_event();
+        }
+
+        if let Some(on_drop) = &self.on_drop {
+            shell.publish(on_drop(index, target_index));
+            shell.capture_event();
+        }
+
+        if let Some(on_reorder) = &self.on_reorder {
+            shell.publish(on_reorder(self.new_order(index, target_index)));
+            shell.capture_event();
+        }
+
+        self.publish_drag_end(shell);
+    }
+
+    /// Handles a key press while the [`Row`] is focused, returning whether
+    /// the key was used.
+    fn grab_key(
+        &self,
+        key: keyboard::key::Named,
+        action: &mut Action,
+        selected: &mut usize,
+        modifiers: keyboard::Modifiers,
+        layout: Layout<'_>,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        use keyboard::key::Named;
+
+        let last = self.children.len().saturating_sub(1);
+
+        match action {
+            Action::Idle { animations, .. } => match key {
+                Named::ArrowLeft => {
+                    *selected = selected.saturating_sub(1);
+                    true
+                }
+                Named::ArrowRight => {
+                    *selected = (*selected + 1).min(last);
+                    true
+                }
+                Named::Space => {
+                    let index = *selected;
+
+                    if !self.can_pick(index) {
+                        return false;
+                    }
+
+                    animations.with_capacity(self.children.len());
+                    animations.zero();
+
+                    *action = Action::Grabbing {
+                        index,
+                        target_index: index,
+                        now: Instant::now(),
+                        animations: std::mem::take(animations),
+                    };
+
+                    self.publish_pick(index, shell);
+
+                    true
+                }
+                _ => false,
+            },
+            Action::Grabbing {
+                index,
+                target_index,
+                animations,
+                ..
+            } => match key {
+                Named::ArrowLeft | Named::ArrowRight => {
+                    let new_target = if key == Named::ArrowLeft {
+                        target_index.saturating_sub(1)
+                    } else {
+                        (*target_index + 1).min(last)
+                    };
+
+                    if new_target != *target_index {
+                        *target_index = new_target;
+
+                        self.animate_grab(
+                            layout, *index, new_target, animations,
+                        );
+
+                        if let Some(on_drag_over) = &self.on_drag_over {
+                            shell.publish(on_drag_over(*index, new_target));
+                        }
+                    }
+
+                    true
+                }
+                Named::Space | Named::Enter => {
+                    let index = *index;
+                    let target_index = *target_index;
+
+                    *selected = target_index;
+
+                    if self.reorder_after_animation {
+                        *action = Action::Settling {
+                            index,
+                            target_index,
+                            modifiers,
+                            now: Instant::now(),
+                            animations: std::mem::take(animations),
+                        };
+
+                        return true;
+                    }
+
+                    // The application reorders its items on drop, so the
+                    // offsets must not be applied on top of the new layout
+                    self.reflow(
+                        layout,
+                        &self.new_order(index, target_index),
+                        None,
+                        false,
+                        animations,
+                    );
+
+                    *action = Action::Idle {
+                        now: Some(Instant::now()),
+                        animations: std::mem::take(animations),
+                    };
+
+                    self.publish_drop(index, target_index, modifiers, shell);
+
+                    true
+                }
+                Named::Escape => {
+                    self.cancel_grab(action, shell);
+
+                    true
+                }
+                _ => false,
+            },
+            Action::Picking { .. }
+            | Action::Dragging { .. }
+            | Action::Settling { .. } => false,
+        }
+    }
+
+    /// Finishes settling the dropped item, if any, emitting its drop.
+    fn finish_settling(
+        &self,
+        action: &mut Action,
+        selected: &mut usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Action::Settling {
+            index,
+            target_index,
+            modifiers,
+            animations,
+            ..
+        } = action
+        {
+            let index = *index;
+            let target_index = *target_index;
+            let modifiers = *modifiers;
+
+            // The application reorders its items on drop, so the offsets
+            // must not be applied on top of the new layout
+            animations.zero();
+
+            *selected = target_index;
+            *action = Action::Idle {
+                now: Some(Instant::now()),
+                animations: std::mem::take(animations),
+            };
+
+            self.publish_drop(index, target_index, modifiers, shell);
+            shell.request_redraw();
+        }
+    }
+
+    /// Returns the index of the item containing the focused widget, if any.
+    fn focused_item(
+        &mut self,
+        trees: &mut [Tree],
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<usize> {
+        self.children
+            .iter_mut()
+            .zip(trees)
+            .zip(layout.children())
+            .position(|((child, state), layout)| {
+                let mut operation = ContainsFocus::default();
+
+                child.as_widget_mut().operate(
+                    state,
+                    layout,
+                    renderer,
+                    &mut operation as &mut dyn Operation<()>,
+                );
+
+                operation.is_focused
+            })
+    }
+
+    /// Cancels the mouse drag, if any, moving every item back into place.
+    fn cancel_drag(&self, action: &mut Action, shell: &mut Shell<'_, Message>) {
+        if let Action::Dragging {
+            index, animations, ..
+        } = action
+        {
+            let index = *index;
+            let now = Instant::now();
+
+            for animation in &mut animations.offsets {
+                animation.go_mut(0.0, now);
+            }
+
+            *action = Action::Idle {
+                now: Some(now),
+                animations: std::mem::take(animations),
+            };
+
+            self.publish_cancel(index, shell);
+            shell.request_redraw();
+        }
+    }
+
+    /// Cancels the keyboard grab, if any, moving every item back into place.
+    fn cancel_grab(&self, action: &mut Action, shell: &mut Shell<'_, Message>) {
+        if let Action::Grabbing {
+            index, animations, ..
+        } = action
+        {
+            let index = *index;
+            let now = Instant::now();
+
+            for animation in &mut animations.offsets {
+                animation.go_mut(0.0, now);
+            }
+
+            *action = Action::Idle {
+                now: Some(now),
+                animations: std::mem::take(animations),
+            };
+
+            self.publish_cancel(index, shell);
+            shell.request_redraw();
+        }
+    }
+
+    /// Animates the items of the [`Row`] to make room for the item at `index`
+    /// dragged to `cursor_position`, or to move them back into place if the
+    /// item is over another container.
+    fn animate_drag(
+        &self,
+        layout: Layout<'_>,
+        index: usize,
+        cursor_position: Point,
+        is_transferring: bool,
+        animations: &mut ItemAnimations,
+    ) {
+        animations.with_capacity(self.children.len());
+
+        let target_index = match self.drop_preview {
+            DropPreview::MakeRoom if !is_transferring => {
+                self.compute_drop_index(cursor_position, layout, index)
+            }
+            DropPreview::MakeRoom | DropPreview::Line => index,
+        };
+
+        let drag_width =
+            if let Some(child_layout) = layout.children().nth(index) {
+                child_layout.bounds().width + self.spacing
+            } else {
+                0.0
+            };
+
+        for i in 0..animations.offsets.len() {
+            if i == index {
+                animations.offsets[i].go_mut(1.0, Instant::now());
+                continue;
+            }
+
+            let target_offset = match target_index.cmp(&index) {
+                std::cmp::Ordering::Less
+                    if (target_index..index).contains(&i) =>
+                {
+                    drag_width
+                }
+                std::cmp::Ordering::Greater
+                    if (index + 1..=target_index).contains(&i) =>
+                {
+                    -drag_width
+                }
+                _ => 0.0,
+            };
 
-    /// Sets the height of the [`Row`].
-    pub fn height(mut self, height: impl Into<Length>) -> Self {
-        self.height = height.into();
-        self
+            animations.offsets[i].go_mut(target_offset, Instant::now());
+        }
     }
 
-    /// Sets the vertical alignment of the contents of the [`Row`] .
-    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
-        self.align = Alignment::from(align.into());
-        self
-    }
+    /// Animates the items of the [`Row`] to make room for the grabbed item at
+    /// `index` in `target_index`.
+    fn animate_grab(
+        &self,
+        layout: Layout<'_>,
+        index: usize,
+        target_index: usize,
+        animations: &mut ItemAnimations,
+    ) {
+        let size = |layout: Layout<'_>| layout.bounds().width + self.spacing;
+        let drag_size = layout.children().nth(index).map_or(0.0, size);
+        let now = Instant::now();
 
-    /// Sets whether the contents of the [`Row`] should be clipped on
-    /// overflow.
-    pub fn clip(mut self, clip: bool) -> Self {
-        self.clip = clip;
-        self
-    }
+        animations.with_capacity(self.children.len());
 
-    /// Sets the drag deadband zone of the [`Row`].
-    ///
-    /// This is the minimum distance in pixels that the cursor must move
-    /// before a drag operation begins. Default is 5.0 pixels.
-    pub fn deadband_zone(mut self, deadband_zone: f32) -> Self {
-        self.deadband_zone = deadband_zone;
-        self
-    }
+        let mut grabbed_offset = 0.0;
 
-    /// Adds an [`Element`] to the [`Row`].
-    pub fn push(
-        mut self,
-        child: impl Into<Element<'a, Message, Theme, Renderer>>,
-    ) -> Self {
-        let child = child.into();
-        let child_size = child.as_widget().size_hint();
+        for (i, child_layout) in layout.children().enumerate() {
+            let offset = match target_index.cmp(&index) {
+                std::cmp::Ordering::Less
+                    if (target_index..index).contains(&i) =>
+                {
+                    grabbed_offset -= size(child_layout);
+                    drag_size
+                }
+                std::cmp::Ordering::Greater
+                    if (index + 1..=target_index).contains(&i) =>
+                {
+                    grabbed_offset += size(child_layout);
+                    -drag_size
+                }
+                _ => 0.0,
+            };
 
-        if !child_size.is_void() {
-            self.width = self.width.enclose(child_size.width);
-            self.height = self.height.enclose(child_size.height);
-            self.children.push(child);
+            if i != index {
+                animations.offsets[i].go_mut(offset, now);
+            }
         }
 
-        self
+        animations.offsets[index].go_mut(grabbed_offset, now);
     }
 
-    /// Adds an element to the [`Row`], if `Some`.
-    pub fn push_maybe(
-        self,
-        child: Option<impl Into<Element<'a, Message, Theme, Renderer>>>,
-    ) -> Self {
-        if let Some(child) = child {
-            self.push(child)
-        } else {
-            self
-        }
-    }
+    /// Draws a line in the gap where the item at `index` will be dropped if
+    /// released at `target_index`.
+    fn draw_drop_line(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        index: usize,
+        target_index: usize,
+        style: &Style,
+    ) {
+        let slot = match target_index.cmp(&index) {
+            std::cmp::Ordering::Less => target_index,
+            std::cmp::Ordering::Greater => target_index + 1,
+            std::cmp::Ordering::Equal => return,
+        };
 
-    /// Sets the style of the [`Row`].
-    #[must_use]
-    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
-    where
-        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
-    {
-        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
-        self
+        self.draw_insertion_line(renderer, layout, slot, style);
     }
 
-    /// Sets the style class of the [`Row`].
-    #[must_use]
-    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
-        self.class = class.into();
-        self
+    /// Draws a line in the gap before the item at `slot`, or after the last
+    /// item if there is none.
+    fn draw_insertion_line(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        slot: usize,
+        style: &Style,
+    ) {
+        let bounds = layout.bounds();
+
+        let x = if let Some(next) = layout.children().nth(slot) {
+            next.bounds().x - self.spacing / 2.0
+        } else if let Some(last) = layout.children().last() {
+            let last = last.bounds();
+
+            last.x + last.width + self.spacing / 2.0
+        } else {
+            bounds.x + self.padding.left
+        };
+
+        let thickness = style.drop_indicator_width;
+
+        // Keep the line within the Row when dropping at either end
+        let x = x.clamp(
+            bounds.x + thickness / 2.0,
+            (bounds.x + bounds.width - thickness / 2.0)
+                .max(bounds.x + thickness / 2.0),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: bounds.y + self.padding.top,
+                    x: x - thickness / 2.0,
+                    height: bounds.height - self.padding.y(),
+                    width: thickness,
+                },
+                border: border::rounded(thickness / 2.0),
+                ..renderer::Quad::default()
+            },
+            style.drop_indicator,
+        );
     }
 
-    /// Extends the [`Row`] with the given children.
-    pub fn extend(
-        self,
-        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
-    ) -> Self {
-        children.into_iter().fold(self, Self::push)
+    /// Returns the position among the items of the [`Row`] where an item
+    /// dragged out of another container would be inserted.
+    fn compute_insert_index(
+        &self,
+        cursor_position: Point,
+        layout: Layout<'_>,
+    ) -> usize {
+        layout
+            .children()
+            .position(|child| cursor_position.x < child.bounds().center_x())
+            .unwrap_or(self.children.len())
     }
 
-    /// Sets a handler for drag events.
-    ///
-    /// When set, items in the [`Row`] can be dragged and reordered.
-    /// The handler receives a [`DragEvent`] describing what happened.
-    pub fn on_drag(
-        mut self,
-        on_drag: impl Fn(DragEvent) -> Message + 'a,
-    ) -> Self {
-        self.on_drag = Some(Box::new(on_drag));
-        self
+    /// Computes the index where the item at `index` should be dropped,
+    /// following the [`DropZones`] of the [`Row`].
+    fn compute_drop_index(
+        &self,
+        cursor_position: Point,
+        layout: Layout<'_>,
+        index: usize,
+    ) -> usize {
+        // Items stay in place while over a drop target
+        if self.drop_target_at(cursor_position, layout).is_some() {
+            return index;
+        }
+
+        let target_index = self.compute_target_index(cursor_position, layout);
+
+        match self.drop_zones {
+            DropZones::Midpoint => target_index,
+            DropZones::HalfItem => {
+                let Some(target) = layout.children().nth(target_index) else {
+                    return target_index;
+                };
+
+                // The slot between items where the dragged item goes
+                let slot = if cursor_position.x < target.bounds().center_x() {
+                    target_index
+                } else {
+                    target_index + 1
+                };
+
+                // The dragged item leaves its own slot when moving right
+                if slot > index { slot - 1 } else { slot }
+            }
+        }
     }
 
     /// Computes the index where a dragged item should be dropped.
@@ -272,6 +1494,36 @@ where
     }
 }
 
+/// Returns the distance to scroll by while dragging an item at `x`, given the
+/// bounds of the [`Row`], the visible viewport and the width of the scrolling
+/// area along its edges.
+///
+/// The closer to an edge, the faster it scrolls.
+fn drag_scroll_delta(
+    x: f32,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+    margin: f32,
+) -> f32 {
+    let start = viewport.x;
+    let end = viewport.x + viewport.width;
+
+    if margin <= 0.0 {
+        return 0.0;
+    }
+
+    let speed =
+        |distance: f32| DRAG_SCROLL_SPEED * (1.0 - distance.max(0.0) / margin);
+
+    if x < start + margin && bounds.x < start {
+        -speed(x - start)
+    } else if x > end - margin && bounds.x + bounds.width > end {
+        speed(end - x)
+    } else {
+        0.0
+    }
+}
+
 impl<Message, Theme, Renderer> Default for Row<'_, Message, Theme, Renderer>
 where
     Renderer: crate::core::Renderer,
@@ -297,6 +1549,32 @@ where
     }
 }
 
+// Internal state of the widget
+#[derive(Debug, Clone, Default)]
+struct State {
+    action: Action,
+    modifiers: keyboard::Modifiers,
+    is_focused: bool,
+    selected: usize,
+    drag_key: u64,
+    drop_hover: Option<usize>,
+    is_transferring: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 // Internal state for drag animations
 #[derive(Debug, Clone)]
 enum Action {
@@ -317,6 +1595,19 @@ enum Action {
         now: Instant,
         animations: ItemAnimations,
     },
+    Grabbing {
+        index: usize,
+        target_index: usize,
+        now: Instant,
+        animations: ItemAnimations,
+    },
+    Settling {
+        index: usize,
+        target_index: usize,
+        modifiers: keyboard::Modifiers,
+        now: Instant,
+        animations: ItemAnimations,
+    },
 }
 
 impl Default for Action {
@@ -331,6 +1622,8 @@ impl Default for Action {
 #[derive(Default, Debug, Clone)]
 struct ItemAnimations {
     offsets: Vec<Animation<f32>>,
+    insertions: Vec<Animation<f32>>,
+    count: usize,
 }
 
 impl ItemAnimations {
@@ -341,7 +1634,37 @@ impl ItemAnimations {
     }
 
     fn is_animating(&self, now: Instant) -> bool {
-        self.offsets.iter().any(|anim| anim.is_animating(now))
+        self.offsets
+            .iter()
+            .chain(&self.insertions)
+            .any(|anim| anim.is_animating(now))
+    }
+
+    /// Starts the insertion animation of the items added since the last
+    /// diff, if any.
+    fn insert(&mut self, count: usize, duration: Option<Duration>) {
+        if let Some(duration) = duration
+            && count > self.count
+        {
+            let now = Instant::now();
+
+            self.insertions.resize_with(count, || Animation::new(1.0));
+
+            for animation in &mut self.insertions[self.count..count] {
+                *animation =
+                    Animation::new(0.0).duration(duration).go(1.0, now);
+            }
+        }
+
+        self.insertions.truncate(count);
+        self.count = count;
+    }
+
+    fn insertion_scale(&self, index: usize, now: Instant) -> f32 {
+        self.insertions
+            .get(index)
+            .filter(|animation| animation.is_animating(now))
+            .map_or(1.0, |animation| animation.interpolate_with(|v| v, now))
     }
 
     fn with_capacity(&mut self, count: usize) {
@@ -358,16 +1681,21 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<Action>()
+        tree::Tag::of::<State>()
     }
 
     fn state(&self) -> tree::State {
         let mut animations = ItemAnimations::default();
         animations.with_capacity(self.children.len());
+        animations.count = self.children.len();
 
-        tree::State::new(Action::Idle {
-            now: Some(Instant::now()),
-            animations,
+        tree::State::new(State {
+            action: Action::Idle {
+                now: Some(Instant::now()),
+                animations,
+            },
+            drag_key: Session::next_key(),
+            ..State::default()
         })
     }
 
@@ -378,13 +1706,20 @@ where
     fn diff(&self, tree: &mut Tree) {
         tree.diff_children(&self.children);
 
-        let action = tree.state.downcast_mut::<Action>();
+        let State {
+            action, selected, ..
+        } = tree.state.downcast_mut::<State>();
+
+        *selected = (*selected).min(self.children.len().saturating_sub(1));
 
         match action {
             Action::Idle { animations, .. }
             | Action::Picking { animations, .. }
-            | Action::Dragging { animations, .. } => {
+            | Action::Dragging { animations, .. }
+            | Action::Grabbing { animations, .. }
+            | Action::Settling { animations, .. } => {
                 animations.with_capacity(self.children.len());
+                animations.insert(self.children.len(), self.insertion_duration);
             }
         }
     }
@@ -423,6 +1758,12 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        if self.is_draggable() {
+            let state = tree.state.downcast_mut::<State>();
+
+            operation.focusable(None, layout.bounds(), state);
+        }
+
         operation.container(None, layout.bounds());
         operation.traverse(&mut |operation| {
             self.children
@@ -448,7 +1789,15 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        let action = tree.state.downcast_mut::<Action>();
+        let State {
+            action,
+            modifiers,
+            is_focused,
+            selected,
+            drag_key,
+            drop_hover,
+            is_transferring,
+        } = tree.state.downcast_mut::<State>();
 
         for ((child, state), layout) in self
             .children
@@ -462,11 +1811,120 @@ where
             );
         }
 
+        // Track items dragged out of other containers, even if captured
+        if let Some(group) = &self.drag_group {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    let slot =
+                        cursor.position_over(layout.bounds()).map(|position| {
+                            self.compute_insert_index(position, layout)
+                        });
+
+                    let is_dragging_in = Session::hover(*drag_key, group, slot);
+                    let slot = slot.filter(|_| is_dragging_in);
+
+                    if slot != *drop_hover {
+                        *drop_hover = slot;
+                        shell.request_redraw();
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) => {
+                    Session::release();
+
+                    if drop_hover.is_some() {
+                        *drop_hover = None;
+                        shell.request_redraw();
+                    }
+                }
+                _ => {}
+            }
+        }
+
         if shell.is_event_captured() {
             return;
         }
 
+        // Unfocusing (e.g. with Tab) cancels the keyboard grab
+        if !*is_focused {
+            self.cancel_grab(action, shell);
+        }
+
         match &event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                new_modifiers,
+            )) => {
+                *modifiers = *new_modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if matches!(action, Action::Dragging { .. }) => {
+                let _ = Session::end(*drag_key);
+
+                self.cancel_drag(action, shell);
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        key @ (keyboard::key::Named::ArrowLeft
+                        | keyboard::key::Named::ArrowRight),
+                    ),
+                modifiers: key_modifiers,
+                ..
+            }) if self.keyboard_reorder
+                && key_modifiers.alt()
+                && self.is_draggable()
+                && matches!(action, Action::Idle { .. }) =>
+            {
+                let Some(index) =
+                    self.focused_item(&mut tree.children, layout, renderer)
+                else {
+                    return;
+                };
+
+                let target_index = if *key == keyboard::key::Named::ArrowLeft {
+                    index.checked_sub(1)
+                } else {
+                    Some(index + 1).filter(|i| *i < self.children.len())
+                };
+
+                if let Some(target_index) = target_index
+                    && self.can_pick(index)
+                {
+                    // The state follows the item, keeping its focus
+                    tree.children.swap(index, target_index);
+
+                    *selected = target_index;
+
+                    if let Action::Idle { animations, .. } = action {
+                        self.reflow(
+                            layout,
+                            &self.new_order(index, target_index),
+                            None,
+                            true,
+                            animations,
+                        );
+                    }
+
+                    self.publish_drop(index, target_index, *modifiers, shell);
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if *is_focused && self.is_draggable() => {
+                if self
+                    .grab_key(*key, action, selected, *modifiers, layout, shell)
+                {
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
             Event::Window(crate::core::window::Event::RedrawRequested(now)) => {
                 match action {
                     Action::Idle {
@@ -481,30 +1939,111 @@ where
                     }
                     Action::Picking {
                         now: current_now, ..
+                    } => {
+                        *current_now = *now;
+                        shell.request_redraw();
                     }
-                    | Action::Dragging {
-                        now: current_now, ..
+                    Action::Dragging {
+                        index,
+                        last_cursor,
+                        now: current_now,
+                        animations,
+                        ..
                     } => {
                         *current_now = *now;
                         shell.request_redraw();
+
+                        if let Some(cursor_position) = cursor.land().position()
+                        {
+                            let is_leaving =
+                                Session::is_transferring(*drag_key);
+
+                            // Scrolling moves the items under a still cursor
+                            if cursor_position != *last_cursor
+                                || is_leaving != *is_transferring
+                            {
+                                *last_cursor = cursor_position;
+                                *is_transferring = is_leaving;
+
+                                self.animate_drag(
+                                    layout,
+                                    *index,
+                                    cursor_position,
+                                    is_leaving,
+                                    animations,
+                                );
+                            }
+
+                            if let Some(on_drag_scroll) = &self.on_drag_scroll {
+                                let delta = drag_scroll_delta(
+                                    cursor_position.x,
+                                    layout.bounds(),
+                                    viewport,
+                                    self.drag_scroll_margin,
+                                );
+
+                                if delta != 0.0 {
+                                    shell.publish(on_drag_scroll(delta));
+                                }
+                            }
+                        }
+                    }
+                    Action::Grabbing {
+                        now: current_now,
+                        animations,
+                        ..
+                    } => {
+                        *current_now = *now;
+
+                        if animations.is_animating(*now) {
+                            shell.request_redraw();
+                        }
+                    }
+                    Action::Settling {
+                        now: current_now,
+                        animations,
+                        ..
+                    } => {
+                        *current_now = *now;
+
+                        if animations.is_animating(*now) {
+                            shell.request_redraw();
+                        } else {
+                            self.finish_settling(action, selected, shell);
+                        }
                     }
                 }
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if self.on_drag.is_some() {
+                if let Action::Settling { .. } = action {
+                    self.finish_settling(action, selected, shell);
+
+                    if cursor.is_over(layout.bounds()) {
+                        shell.capture_event();
+                    }
+                } else if self.is_draggable() {
+                    self.cancel_grab(action, shell);
+
                     if let Some(cursor_position) =
                         cursor.position_over(layout.bounds())
+                        && self.can_pick(
+                            self.compute_target_index(cursor_position, layout),
+                        )
                     {
                         let animations = match action {
-                            Action::Idle { animations, .. } => animations,
-                            Action::Picking { animations, .. } => animations,
-                            Action::Dragging { animations, .. } => animations,
+                            Action::Idle { animations, .. }
+                            | Action::Picking { animations, .. }
+                            | Action::Dragging { animations, .. }
+                            | Action::Grabbing { animations, .. }
+                            | Action::Settling { animations, .. } => animations,
                         };
                         animations.zero();
 
                         let index =
                             self.compute_target_index(cursor_position, layout);
 
+                        *selected = index;
+
                         *action = Action::Picking {
                             index,
                             origin: cursor_position,
@@ -526,7 +2065,7 @@ where
                 } => {
                     if let Some(cursor_position) = cursor.position() {
                         if cursor_position.distance(*origin)
-                            > self.deadband_zone
+                            > self.drag_threshold
                         {
                             let index = *index;
                             let origin = *origin;
@@ -542,87 +2081,45 @@ where
 
                             shell.request_redraw();
 
-                            if let Some(on_drag) = &self.on_drag {
-                                shell.publish(on_drag(DragEvent::Picked {
-                                    index,
-                                }));
-                            }
-
+                            self.publish_pick(index, shell);
                             shell.capture_event();
+
+                            if let Some(group) = &self.drag_group
+                                && self.on_drag_out.is_some()
+                            {
+                                Session::start(*drag_key, group, index);
+                            }
                         }
                     }
                 }
                 Action::Dragging {
-                    origin,
                     index,
+                    last_cursor,
                     now,
                     animations,
                     ..
                 } => {
                     shell.request_redraw();
 
-                    if let Some(cursor_position) = cursor.position() {
-                        animations.with_capacity(self.children.len());
-
-                        let target_index =
-                            self.compute_target_index(cursor_position, layout);
-
-                        let drag_width = if let Some(child_layout) =
-                            layout.children().nth(*index)
-                        {
-                            child_layout.bounds().width + self.spacing
-                        } else {
-                            0.0
-                        };
-
-                        for i in 0..animations.offsets.len() {
-                            if i == *index {
-                                animations.offsets[i]
-                                    .go_mut(1.0, Instant::now());
-                                continue;
-                            }
-
-                            let target_offset = match target_index.cmp(index) {
-                                std::cmp::Ordering::Less
-                                    if (target_index..*index).contains(&i) =>
-                                {
-                                    drag_width
-                                }
-                                std::cmp::Ordering::Greater
-                                    if (*index + 1..=target_index)
-                                        .contains(&i) =>
-                                {
-                                    -drag_width
-                                }
-                                _ => 0.0,
-                            };
-
-                            animations.offsets[i]
-                                .go_mut(target_offset, Instant::now());
-                        }
-
-                        let origin = *origin;
-                        let index = *index;
-                        let now = *now;
+                    if let Some(cursor_position) = cursor.land().position() {
+                        *last_cursor = cursor_position;
+                        *is_transferring = Session::is_transferring(*drag_key);
 
-                        *action = Action::Dragging {
-                            last_cursor: cursor_position,
-                            origin,
-                            index,
-                            now,
-                            animations: std::mem::take(animations),
-                        };
+                        self.animate_drag(
+                            layout,
+                            *index,
+                            cursor_position,
+                            *is_transferring,
+                            animations,
+                        );
 
                         shell.capture_event();
                     } else {
                         let index = *index;
                         let now = *now;
 
-                        if let Some(on_drag) = &self.on_drag {
-                            shell.publish(on_drag(DragEvent::Canceled {
-                                index,
-                            }));
-                        }
+                        let _ = Session::end(*drag_key);
+                        self.publish_cancel(index, shell);
 
                         *action = Action::Idle {
                             now: Some(now),
@@ -636,6 +2133,7 @@ where
                 match action {
                     Action::Dragging {
                         index,
+                        origin,
                         animations,
                         now,
                         ..
@@ -643,10 +2141,72 @@ where
                         let current_now = *now;
 
                         animations.with_capacity(self.children.len());
+                        *is_transferring = false;
+
+                        // The item leaves for another container
+                        if let Some(transfer) = Session::end(*drag_key)
+                            && let Some(on_drag_out) = &self.on_drag_out
+                        {
+                            for animation in &mut animations.offsets {
+                                animation.go_mut(0.0, Instant::now());
+                            }
+
+                            *action = Action::Idle {
+                                now: Some(current_now),
+                                animations: std::mem::take(animations),
+                            };
+
+                            shell.publish(on_drag_out(transfer));
+                            self.publish_drag_end(shell);
+                            shell.capture_event();
+                            shell.request_redraw();
+                            return;
+                        }
+
+                        if let Some(cursor_position) = cursor.land().position()
+                        {
+                            let target_index = self.compute_drop_index(
+                                cursor_position,
+                                layout,
+                                *index,
+                            );
 
-                        if let Some(cursor_position) = cursor.position() {
-                            let target_index = self
-                                .compute_target_index(cursor_position, layout);
+                            if self.reorder_after_animation
+                                && target_index != *index
+                            {
+                                let index = *index;
+
+                                // Slide the item from the cursor to its slot
+                                animations.offsets[index] = Animation::new(
+                                    cursor_position.x - origin.x,
+                                );
+
+                                self.animate_grab(
+                                    layout,
+                                    index,
+                                    target_index,
+                                    animations,
+                                );
+
+                                *action = Action::Settling {
+                                    index,
+                                    target_index,
+                                    modifiers: *modifiers,
+                                    now: current_now,
+                                    animations: std::mem::take(animations),
+                                };
+
+                                shell.capture_event();
+                                shell.request_redraw();
+                                return;
+                            }
+
+                            // With a drop line, the items have not moved and
+                            // are laid out again in their new order at once
+                            let shifted_index = match self.drop_preview {
+                                DropPreview::MakeRoom => target_index,
+                                DropPreview::Line => *index,
+                            };
 
                             let drag_width = if let Some(child_layout) =
                                 layout.children().nth(*index)
@@ -658,15 +2218,15 @@ where
 
                             for i in 0..animations.offsets.len() {
                                 let target_offset =
-                                    match target_index.cmp(index) {
+                                    match shifted_index.cmp(index) {
                                         std::cmp::Ordering::Less
-                                            if (target_index..*index)
+                                            if (shifted_index..*index)
                                                 .contains(&i) =>
                                         {
                                             drag_width
                                         }
                                         std::cmp::Ordering::Greater
-                                            if (*index + 1..=target_index)
+                                            if (*index + 1..=shifted_index)
                                                 .contains(&i) =>
                                         {
                                             -drag_width
@@ -683,17 +2243,35 @@ where
                                 }
                             }
 
-                            if let Some(on_drag) = &self.on_drag {
-                                shell.publish(on_drag(DragEvent::Dropped {
-                                    index: *index,
+                            // Over a drop target, the items stay in place
+                            if let Some((_, id)) =
+                                self.drop_target_at(cursor_position, layout)
+                                && let Some(on_drop_target) =
+                                    &self.on_drop_target
+                            {
+                                shell.publish(on_drop_target(*index, id));
+                                self.publish_drag_end(shell);
+                            } else {
+                                self.reflow(
+                                    layout,
+                                    &self.new_order(*index, target_index),
+                                    Some((
+                                        *index,
+                                        cursor_position.x - origin.x,
+                                    )),
+                                    false,
+                                    animations,
+                                );
+
+                                self.publish_drop(
+                                    *index,
                                     target_index,
-                                }));
-                                shell.capture_event();
+                                    *modifiers,
+                                    shell,
+                                );
                             }
-                        } else if let Some(on_drag) = &self.on_drag {
-                            shell.publish(on_drag(DragEvent::Canceled {
-                                index: *index,
-                            }));
+                        } else {
+                            self.publish_cancel(*index, shell);
                             shell.capture_event();
                         }
 
@@ -726,9 +2304,9 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let action = tree.state.downcast_ref::<Action>();
+        let action = &tree.state.downcast_ref::<State>().action;
 
-        if let Action::Dragging { .. } = *action {
+        if let Action::Dragging { .. } = action {
             return mouse::Interaction::Grabbing;
         }
 
@@ -755,7 +2333,14 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let action = tree.state.downcast_ref::<Action>();
+        let State {
+            action,
+            is_focused,
+            selected,
+            drop_hover,
+            is_transferring,
+            ..
+        } = tree.state.downcast_ref::<State>();
         let style = theme.style(&self.class);
 
         match action {
@@ -769,23 +2354,28 @@ where
             } => {
                 let child_count = self.children.len();
 
-                let target_index = if cursor.position().is_some() {
-                    let target_index =
-                        self.compute_target_index(*last_cursor, layout);
-                    target_index.min(child_count - 1)
-                } else {
+                let target_index = if *is_transferring {
                     *index
+                } else {
+                    self.compute_drop_index(*last_cursor, layout, *index)
+                        .min(child_count - 1)
                 };
 
                 let drag_bounds =
                     layout.children().nth(*index).unwrap().bounds();
                 let drag_width = drag_bounds.width + self.spacing;
 
+                let moved = self.moved_items(*index).unwrap_or_default();
+
                 for i in 0..child_count {
                     let child = &self.children[i];
                     let state = &tree.children[i];
                     let child_layout = layout.children().nth(i).unwrap();
 
+                    if i == *index && self.drag_preview.is_some() {
+                        continue;
+                    }
+
                     if i == *index {
                         let scale_factor = 1.0
                             + (style.scale - 1.0)
@@ -793,10 +2383,42 @@ where
                                     .interpolate_with(|v| v, *now);
 
                         let scaling = Transformation::scale(scale_factor);
-                        let translation = *last_cursor - *origin * scaling;
+                        let cursor_position = if self.axis_lock {
+                            Point::new(last_cursor.x, origin.y)
+                        } else {
+                            *last_cursor
+                        };
+
+                        // The grabbed point stays under the cursor
+                        let translation = cursor_position - *origin * scaling;
+
+                        let stacked = (moved.len().max(1) - 1).min(2);
 
                         renderer.with_translation(translation, |renderer| {
                             renderer.with_transformation(scaling, |renderer| {
+                                // The rest of the selection is stacked below
+                                for k in (1..=stacked).rev() {
+                                    let offset = STACK_OFFSET * k as f32;
+
+                                    renderer.fill_quad(
+                                        renderer::Quad {
+                                            bounds: child_layout.bounds()
+                                                + Vector::new(offset, offset),
+                                            border: style.ghost_border,
+                                            ..renderer::Quad::default()
+                                        },
+                                        style.ghost_background,
+                                    );
+                                }
+
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: child_layout.bounds(),
+                                        ..renderer::Quad::default()
+                                    },
+                                    style.dragged_background,
+                                );
+
                                 renderer.with_layer(
                                     child_layout.bounds(),
                                     |renderer| {
@@ -820,7 +2442,9 @@ where
                             0.0
                         };
 
-                        let offset = if base_offset == 0.0 {
+                        let offset = if base_offset == 0.0
+                            && self.drop_preview == DropPreview::MakeRoom
+                        {
                             match target_index.cmp(index) {
                                 std::cmp::Ordering::Less
                                     if i >= target_index && i < *index =>
@@ -864,12 +2488,40 @@ where
                                         .scale_alpha(progress),
                                 );
                             }
+
+                            if moved.contains(&i) {
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: child_layout.bounds(),
+                                        border: style.ghost_border,
+                                        ..renderer::Quad::default()
+                                    },
+                                    style.ghost_background,
+                                );
+                            }
+
+                            if let Some(alpha) = self.dim_others
+                                && !is_drop_neighbor(i, *index, target_index)
+                            {
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: child_layout.bounds(),
+                                        ..renderer::Quad::default()
+                                    },
+                                    style
+                                        .dimmed_item_overlay
+                                        .scale_alpha(1.0 - alpha),
+                                );
+                            }
                         });
                     }
                 }
 
-                let target_index =
-                    self.compute_target_index(*last_cursor, layout);
+                let target_index = if *is_transferring {
+                    *index
+                } else {
+                    self.compute_drop_index(*last_cursor, layout, *index)
+                };
                 let is_moving_left = target_index < *index;
 
                 let ghost_translation = layout
@@ -913,6 +2565,117 @@ where
                         style.ghost_background,
                     );
                 });
+
+                if self.drop_preview == DropPreview::Line {
+                    self.draw_drop_line(
+                        renderer,
+                        layout,
+                        *index,
+                        target_index,
+                        &style,
+                    );
+                }
+
+                if !*is_transferring
+                    && let Some((target, _)) =
+                        self.drop_target_at(*last_cursor, layout)
+                    && let Some(target_layout) = layout.children().nth(target)
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: target_layout.bounds(),
+                            border: style.ghost_border,
+                            ..renderer::Quad::default()
+                        },
+                        style.ghost_background,
+                    );
+                }
+            }
+            Action::Grabbing {
+                index,
+                now,
+                animations,
+                ..
+            }
+            | Action::Settling {
+                index,
+                now,
+                animations,
+                ..
+            } => {
+                let drag_size = layout
+                    .children()
+                    .nth(*index)
+                    .map_or(0.0, |layout| layout.bounds().width + self.spacing);
+
+                for (i, child) in self.children.iter().enumerate() {
+                    if i == *index {
+                        continue;
+                    }
+
+                    let state = &tree.children[i];
+                    let child_layout = layout.children().nth(i).unwrap();
+                    let offset =
+                        animations.offsets[i].interpolate_with(|v| v, *now);
+
+                    let translation = Vector::new(offset, 0.0);
+
+                    renderer.with_translation(translation, |renderer| {
+                        child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            defaults,
+                            child_layout,
+                            cursor,
+                            viewport,
+                        );
+
+                        if offset != 0.0 {
+                            let progress = (offset / drag_size).abs();
+
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: child_layout.bounds(),
+                                    ..renderer::Quad::default()
+                                },
+                                style.moved_item_overlay.scale_alpha(progress),
+                            );
+                        }
+                    });
+                }
+
+                if let Some(child_layout) = layout.children().nth(*index) {
+                    let offset = animations.offsets[*index]
+                        .interpolate_with(|v| v, *now);
+                    let translation = Vector::new(offset, 0.0);
+
+                    renderer.with_translation(translation, |renderer| {
+                        renderer.with_layer(
+                            child_layout.bounds(),
+                            |renderer| {
+                                self.children[*index].as_widget().draw(
+                                    &tree.children[*index],
+                                    renderer,
+                                    theme,
+                                    defaults,
+                                    child_layout,
+                                    cursor,
+                                    viewport,
+                                );
+
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: child_layout.bounds(),
+                                        border: style.ghost_border,
+                                        ..renderer::Quad::default()
+                                    },
+                                    style.ghost_background,
+                                );
+                            },
+                        );
+                    });
+                }
             }
             Action::Idle {
                 now: Some(now),
@@ -936,17 +2699,34 @@ where
                     };
 
                     let translation = Vector::new(offset, 0.0);
+                    let scale = animations.insertion_scale(i, *now);
 
                     renderer.with_translation(translation, |renderer| {
-                        child.as_widget().draw(
-                            state,
-                            renderer,
-                            theme,
-                            defaults,
-                            child_layout,
-                            cursor,
-                            viewport,
-                        );
+                        let draw = |renderer: &mut Renderer| {
+                            child.as_widget().draw(
+                                state,
+                                renderer,
+                                theme,
+                                defaults,
+                                child_layout,
+                                cursor,
+                                viewport,
+                            );
+                        };
+
+                        if scale == 1.0 {
+                            draw(renderer);
+                        } else {
+                            let center = child_layout.bounds().center();
+                            let scaling = Transformation::scale(scale);
+
+                            renderer.with_translation(
+                                center - center * scaling,
+                                |renderer| {
+                                    renderer.with_transformation(scaling, draw);
+                                },
+                            );
+                        }
 
                         if offset != 0.0 {
                             let width =
@@ -978,6 +2758,26 @@ where
                 }
             }
         }
+
+        // Highlight the item selected with the keyboard
+        if *is_focused
+            && matches!(action, Action::Idle { .. })
+            && let Some(child_layout) = layout.children().nth(*selected)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: child_layout.bounds(),
+                    border: style.ghost_border,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        // Show where an item of another container would land
+        if let Some(slot) = drop_hover {
+            self.draw_insertion_line(renderer, layout, *slot, &style);
+        }
     }
 
     fn overlay<'b>(
@@ -988,14 +2788,48 @@ where
         viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        overlay::from_children(
+        let preview = self.drag_preview.as_ref().and_then(|drag_preview| {
+            let Action::Dragging {
+                index,
+                origin,
+                last_cursor,
+                ..
+            } = &tree.state.downcast_ref::<State>().action
+            else {
+                return None;
+            };
+
+            let bounds = layout.children().nth(*index)?.bounds();
+
+            let cursor_position = if self.axis_lock {
+                Point::new(last_cursor.x, origin.y)
+            } else {
+                *last_cursor
+            };
+
+            // The grabbed point of the preview stays under the cursor
+            let position =
+                cursor_position - (*origin - bounds.position()) + translation;
+
+            Some(DragPreview::new(drag_preview(*index), position).overlay())
+        });
+
+        let children = overlay::from_children(
             &mut self.children,
             tree,
             layout,
             renderer,
             viewport,
             translation,
-        )
+        );
+
+        match (children, preview) {
+            (Some(children), Some(preview)) => Some(
+                overlay::Group::with_children(vec![children, preview])
+                    .overlay(),
+            ),
+            (children, preview) => children.or(preview),
+        }
     }
 }
 
@@ -1011,6 +2845,14 @@ where
     }
 }
 
+/// A function producing the message of a dropped selection of a [`Row`].
+type OnDragMultiFn<'a, Message> =
+    Box<dyn Fn(Vec<usize>, usize) -> Message + 'a>;
+
+/// A function building the preview of a dragged item of a [`Row`].
+type DragPreviewFn<'a, Message, Theme, Renderer> =
+    Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>;
+
 /// The theme catalog of a [`Row`].
 pub trait Catalog {
     /// The item class of the [`Catalog`].
@@ -1028,12 +2870,39 @@ pub trait Catalog {
 pub struct Style {
     /// The scaling to apply to a picked element while it's being dragged.
     pub scale: f32,
+    /// The background drawn behind the dragged item, under the cursor.
+    ///
+    /// The item itself is drawn as is, since widgets cannot be made
+    /// translucent; a solid background keeps it legible over the items it
+    /// is dragged across.
+    pub dragged_background: Background,
     /// The color of the overlay on items that are moved around.
+    ///
+    /// It fades in as the items slide to make room for the dragged item.
     pub moved_item_overlay: Color,
     /// The border of the dragged item's ghost.
+    ///
+    /// The ghost marks the slot where the dragged item will be dropped. It
+    /// is also drawn around the items stacked below a dragged selection, the
+    /// items of the selection left in place, and the item under the cursor
+    /// when dropping onto items.
     pub ghost_border: Border,
     /// The background of the dragged item's ghost.
+    ///
+    /// See [`Style::ghost_border`].
     pub ghost_background: Background,
+    /// The color of the overlay on items dimmed while dragging.
+    ///
+    /// See [`Row::dim_others`].
+    pub dimmed_item_overlay: Color,
+    /// The color of the line drawn where a dragged item will be dropped.
+    ///
+    /// See [`DropPreview::Line`].
+    pub drop_indicator: Color,
+    /// The thickness of the line drawn where a dragged item will be dropped.
+    ///
+    /// See [`DropPreview::Line`].
+    pub drop_indicator_width: f32,
 }
 
 /// A styling function for a [`Row`].
@@ -1055,6 +2924,7 @@ impl Catalog for crate::Theme {
 pub fn default(theme: &crate::Theme) -> Style {
     Style {
         scale: 1.05,
+        dragged_background: Background::Color(Color::TRANSPARENT),
         moved_item_overlay: theme
             .extended_palette()
             .primary
@@ -1073,5 +2943,119 @@ pub fn default(theme: &crate::Theme) -> Style {
             .color
             .scale_alpha(0.2)
             .into(),
+        dimmed_item_overlay: theme.palette().background,
+        drop_indicator: theme.extended_palette().primary.base.color,
+        drop_indicator_width: 2.0,
+    }
+}
+
+/// Returns whether the item at `i` ends up next to the dragged item at
+/// `index` when it is dropped onto `target_index`.
+fn is_drop_neighbor(i: usize, index: usize, target_index: usize) -> bool {
+    // The position of the item once the dragged item is moved
+    let position = if target_index < index && (target_index..index).contains(&i)
+    {
+        i + 1
+    } else if target_index > index && (index + 1..=target_index).contains(&i) {
+        i - 1
+    } else {
+        i
+    };
+
+    position.abs_diff(target_index) == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::Harness;
+
+    use iced_widget::text;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Drag(DragEvent),
+    }
+
+    fn row<'a>(count: usize) -> Row<'a, Message, crate::Theme, ()> {
+        Row::with_children(
+            (0..count).map(|i| text(i).width(50).height(20).into()),
+        )
+        .on_drag(Message::Drag)
+    }
+
+    #[test]
+    fn vertical_jitter_does_not_change_the_target() {
+        let mut harness = Harness::new(row(3), Size::new(300.0, 100.0));
+
+        harness.move_cursor(Point::new(25.0, 10.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+
+        for position in [
+            Point::new(45.0, 14.0),
+            Point::new(70.0, 3.0),
+            Point::new(95.0, 60.0),
+            Point::new(130.0, 90.0),
+            Point::new(128.0, 45.0),
+        ] {
+            harness.move_cursor(position);
+        }
+
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::Drag(DragEvent::Picked { index: 0 }),
+                Message::Drag(DragEvent::Dropped {
+                    index: 0,
+                    target_index: 2,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_at_the_visible_target_when_scrolled() {
+        use iced_runtime::widget::operation::scroll_to;
+        use iced_widget::scrollable::{AbsoluteOffset, Scrollable};
+
+        let scrollable = Scrollable::new(row(6)).id("scrollable").horizontal();
+
+        let mut harness = Harness::new(scrollable, Size::new(150.0, 100.0));
+
+        let _ = harness.run::<()>(scroll_to(
+            "scrollable",
+            AbsoluteOffset {
+                x: Some(150.0),
+                y: None,
+            },
+        ));
+
+        // Item 3 is at the start of the visible half, above the scrollbar
+        harness.move_cursor(Point::new(25.0, 5.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        harness.move_cursor(Point::new(75.0, 5.0));
+        harness.move_cursor(Point::new(125.0, 5.0));
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::Drag(DragEvent::Picked { index: 3 }),
+                Message::Drag(DragEvent::Dropped {
+                    index: 3,
+                    target_index: 5,
+                }),
+            ]
+        );
     }
 }
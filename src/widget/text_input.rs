@@ -4,7 +4,15 @@
 //! methods:
 //!
 //! - [`TextInput::on_focus`] — Emit a message when the input gains focus
+//! - [`TextInput::on_focus_with`] — Emit a message with the current value
+//!   when the input gains focus
 //! - [`TextInput::on_blur`] — Emit a message when the input loses focus
+//! - [`TextInput::validate`] — Validate the value and style the input when
+//!   it is invalid
+//! - [`TextInput::tokens`] — Show entered tokens as removable chips before
+//!   the text
+//! - [`TextInput::suggestions`] — Show a list of suggestions below the input
+//!   as the user types
 //!
 //! [`text_input`]: https://docs.iced.rs/iced/widget/text_input/
 //!
@@ -40,12 +48,14 @@ pub use value::Value;
 use editor::Editor;
 
 use crate::core::alignment;
+use crate::core::border;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse::{self, click};
+use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::paragraph::{self, Paragraph as _};
 use crate::core::text::{self, Text};
@@ -60,6 +70,8 @@ use crate::core::{
     Length, Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Vector,
     Widget,
 };
+use crate::overlay::menu::{self, Menu};
+use crate::widget::operation::{TabIndex, WidgetState};
 
 use iced_runtime::Task;
 
@@ -105,25 +117,60 @@ pub struct TextInput<
     Renderer: text::Renderer,
 {
     id: Option<widget::Id>,
+    tab_index: i32,
     placeholder: String,
+    placeholder_element: Option<Element<'a, Message, Theme, Renderer>>,
+    leading: Option<Element<'a, Message, Theme, Renderer>>,
+    trailing: Option<Element<'a, Message, Theme, Renderer>>,
+    on_clear: Option<Message>,
     value: Value,
     is_secure: bool,
+    select_on_focus: bool,
+    blur_with_window: bool,
+    max_length: Option<usize>,
     font: Option<Renderer::Font>,
     width: Length,
     padding: Padding,
     size: Option<Pixels>,
     line_height: text::LineHeight,
     alignment: alignment::Horizontal,
-    on_focus: Option<Message>,
+    on_focus: Option<OnFocus<'a, Message>>,
     on_blur: Option<Message>,
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_input_debounced: Option<Debounced<'a, Message>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    on_commit: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    commit_on_enter: bool,
+    validate: Option<Validator<'a>>,
+    validate_on: ValidateOn,
+    on_validation: Option<OnValidation<'a, Message>>,
     icon: Option<Icon<Renderer::Font>>,
+    tokens: Vec<String>,
+    on_token_add: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_token_remove: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    suggestions: Vec<String>,
+    on_suggestion: Option<Box<dyn Fn(String) -> Message + 'a>>,
     class: Theme::Class<'a>,
+    menu_class:
+        Option<Box<dyn SuggestionsClass<'a, Message, Theme, Renderer> + 'a>>,
     last_status: Option<Status>,
 }
 
+/// A function validating the value of a [`TextInput`].
+type Validator<'a> = Box<dyn Fn(&str) -> Result<(), String> + 'a>;
+
+/// A function producing a message from the value of a focused [`TextInput`].
+type OnFocus<'a, Message> = Box<dyn Fn(&str) -> Message + 'a>;
+
+/// A function producing a message from the value of a [`TextInput`] once
+/// it is left alone for some time.
+type Debounced<'a, Message> = (Duration, Box<dyn Fn(String) -> Message + 'a>);
+
+/// A function producing a message from the result of a validation.
+type OnValidation<'a, Message> =
+    Box<dyn Fn(Result<(), String>) -> Message + 'a>;
+
 /// The default [`Padding`] of a [`TextInput`].
 pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
 
@@ -138,9 +185,17 @@ where
     pub fn new(placeholder: &str, value: &str) -> Self {
         TextInput {
             id: None,
+            tab_index: 0,
             placeholder: String::from(placeholder),
+            placeholder_element: None,
+            leading: None,
+            trailing: None,
+            on_clear: None,
             value: Value::new(value),
             is_secure: false,
+            select_on_focus: false,
+            blur_with_window: true,
+            max_length: None,
             font: None,
             width: Length::Fill,
             padding: DEFAULT_PADDING,
@@ -150,10 +205,22 @@ where
             on_focus: None,
             on_blur: None,
             on_input: None,
+            on_input_debounced: None,
             on_paste: None,
             on_submit: None,
+            on_commit: None,
+            commit_on_enter: true,
+            validate: None,
+            validate_on: ValidateOn::default(),
+            on_validation: None,
             icon: None,
+            tokens: Vec::new(),
+            on_token_add: None,
+            on_token_remove: None,
+            suggestions: Vec::new(),
+            on_suggestion: None,
             class: Theme::default(),
+            menu_class: None,
             last_status: None,
         }
     }
@@ -164,21 +231,151 @@ where
         self
     }
 
+    /// Sets the position of the [`TextInput`] in the order of
+    /// [`focus_next_by_index`], which is 0 by default.
+    ///
+    /// Widgets are focused in ascending order, and in tree order for equal
+    /// indices. With a negative index, the [`TextInput`] is skipped, but it
+    /// can still be focused by clicking it or with an operation.
+    ///
+    /// [`focus_next_by_index`]: crate::widget::operation::focus_next_by_index
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Sets an [`Element`] to display as the placeholder of the
+    /// [`TextInput`], e.g. a search icon next to some text.
+    ///
+    /// The element is shown in the text area, clipped to it, while the
+    /// [`TextInput`] is empty and unfocused. It is not interactive. While
+    /// focused, the string placeholder is shown instead, if any.
+    pub fn placeholder_element(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.placeholder_element = Some(placeholder.into());
+        self
+    }
+
+    /// Sets an [`Element`] to display inside the [`TextInput`], before its
+    /// text, e.g. a search icon or a currency symbol.
+    ///
+    /// The text is shrunk to make room for it. Clicks on it are handled like
+    /// the ones on the [`trailing`](Self::trailing) element.
+    pub fn leading(
+        mut self,
+        leading: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.leading = Some(leading.into());
+        self
+    }
+
+    /// Sets an [`Element`] to display inside the [`TextInput`], after its
+    /// text, e.g. a button that reveals a password.
+    ///
+    /// The text is shrunk to make room for it. The element gets events
+    /// before the [`TextInput`]: if it captures a click, like a button does,
+    /// the [`TextInput`] is not focused. Otherwise, e.g. for some text, the
+    /// click focuses the [`TextInput`] as usual.
+    pub fn trailing(
+        mut self,
+        trailing: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.trailing = Some(trailing.into());
+        self
+    }
+
+    /// Shows a "×" at the end of the [`TextInput`] while it has some value,
+    /// which produces the given message when clicked, e.g. to clear it.
+    ///
+    /// Clicking the "×" does not change the focus of the [`TextInput`]. Its
+    /// room is kept while the value is empty, so the text never moves. It is
+    /// hidden while the [`TextInput`] is disabled.
+    pub fn clearable(mut self, on_clear: Message) -> Self {
+        self.on_clear = Some(on_clear);
+        self
+    }
+
     /// Converts the [`TextInput`] into a secure password input.
     pub fn secure(mut self, is_secure: bool) -> Self {
         self.is_secure = is_secure;
         self
     }
 
+    /// Sets whether the whole value of the [`TextInput`] is selected when it
+    /// gains focus, so typing replaces it.
+    ///
+    /// This applies to every focus change, whether it comes from a click or
+    /// from an operation like [`focus`]. Clicking an already focused
+    /// [`TextInput`] still moves the cursor.
+    ///
+    /// [`focus`]: https://docs.iced.rs/iced/widget/operation/fn.focus.html
+    pub fn select_on_focus(mut self, select_on_focus: bool) -> Self {
+        self.select_on_focus = select_on_focus;
+        self
+    }
+
+    /// Sets whether a focused [`TextInput`] produces its
+    /// [`on_blur`](Self::on_blur) message when the window loses focus.
+    ///
+    /// By default, it does. The [`TextInput`] keeps its focus and its cursor
+    /// meanwhile, and produces its [`on_focus`](Self::on_focus) message once
+    /// the window is focused again. Its value is not committed.
+    pub fn blur_with_window(mut self, blur_with_window: bool) -> Self {
+        self.blur_with_window = blur_with_window;
+        self
+    }
+
+    /// Sets the maximum length of the value of the [`TextInput`], in
+    /// graphemes.
+    ///
+    /// Typing stops at the limit, and pasted text is truncated to fit; the
+    /// resulting value is produced as usual. A longer value given to the
+    /// [`TextInput`] is shown as is.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused.
-    pub fn on_focus(mut self, on_focus: Message) -> Self {
-        self.on_focus = Some(on_focus);
+    ///
+    /// This includes focus changes made through operations, like
+    /// [`focus`] or [`focus_next`]. Since operations cannot produce
+    /// messages, these are reported with the next event the [`TextInput`]
+    /// receives.
+    ///
+    /// [`focus`]: https://docs.iced.rs/iced/widget/operation/fn.focus.html
+    /// [`focus_next`]: https://docs.iced.rs/iced/widget/operation/fn.focus_next.html
+    pub fn on_focus(mut self, on_focus: Message) -> Self
+    where
+        Message: 'a,
+    {
+        self.on_focus = Some(Box::new(move |_| on_focus.clone()));
+        self
+    }
+
+    /// Sets the closure producing the message when the [`TextInput`] is
+    /// focused, from its current value.
+    ///
+    /// This is useful to remember the value a user started editing from,
+    /// e.g. to restore it on cancel. It replaces any message set with
+    /// [`on_focus`](Self::on_focus), and vice versa.
+    pub fn on_focus_with(
+        mut self,
+        on_focus: impl Fn(&str) -> Message + 'a,
+    ) -> Self {
+        self.on_focus = Some(Box::new(on_focus));
         self
     }
 
     /// Sets the message that should be produced when the [`TextInput`] is
     /// blurred.
+    ///
+    /// Like [`on_focus`](Self::on_focus), this includes the [`TextInput`]
+    /// losing focus through an operation, and the window losing focus; see
+    /// [`blur_with_window`](Self::blur_with_window).
     pub fn on_blur(mut self, on_blur: Message) -> Self {
         self.on_blur = Some(on_blur);
         self
@@ -187,7 +384,8 @@ where
     /// Sets the message that should be produced when some text is typed into
     /// the [`TextInput`].
     ///
-    /// If this method is not called, the [`TextInput`] will be disabled.
+    /// If this method is not called, the [`TextInput`] will be disabled;
+    /// unless it has an [`on_commit`](Self::on_commit) message.
     pub fn on_input(
         mut self,
         on_input: impl Fn(String) -> Message + 'a,
@@ -208,8 +406,30 @@ where
         self
     }
 
+    /// Sets the message that should be produced with the value of the
+    /// [`TextInput`] once the user stops typing for the given [`Duration`],
+    /// e.g. to run an expensive search.
+    ///
+    /// Several quick edits produce a single message, with the last value.
+    /// It can be combined with [`on_input`](Self::on_input) to also get
+    /// every keystroke. Without it, the [`TextInput`] keeps the edited value
+    /// in its state until the message is produced, like with
+    /// [`on_commit`](Self::on_commit); so store the value to keep showing it.
+    pub fn on_input_debounced(
+        mut self,
+        delay: Duration,
+        on_input: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_input_debounced = Some((delay, Box::new(on_input)));
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
+    ///
+    /// Nothing is produced while the [`TextInput`] is disabled, i.e. when it
+    /// has neither an [`on_input`](Self::on_input) nor an
+    /// [`on_commit`](Self::on_commit) handler.
     pub fn on_submit(mut self, message: Message) -> Self {
         self.on_submit = Some(message);
         self
@@ -222,6 +442,72 @@ where
         self
     }
 
+    /// Sets the message that should be produced with the final value of the
+    /// [`TextInput`] when it is committed.
+    ///
+    /// The value is committed every time the [`TextInput`] is blurred and,
+    /// unless disabled with [`commit_on_enter`](Self::commit_on_enter), when
+    /// the enter key is pressed; before any [`on_submit`](Self::on_submit)
+    /// message. Pressing enter does not blur it, so leaving the [`TextInput`]
+    /// afterwards commits once more.
+    ///
+    /// This can be used without [`on_input`](Self::on_input) to only be
+    /// notified of the value when the user is done with it. The [`TextInput`]
+    /// then keeps the edited value in its state until it is committed, and
+    /// shows the value it was created with afterwards; so store the committed
+    /// value to keep showing it.
+    pub fn on_commit(
+        mut self,
+        on_commit: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_commit = Some(Box::new(on_commit));
+        self
+    }
+
+    /// Sets whether pressing enter commits the value of the [`TextInput`].
+    ///
+    /// By default, it does. See [`on_commit`](Self::on_commit).
+    pub fn commit_on_enter(mut self, commit_on_enter: bool) -> Self {
+        self.commit_on_enter = commit_on_enter;
+        self
+    }
+
+    /// Sets the function that validates the value of the [`TextInput`].
+    ///
+    /// It runs when the value changes or the [`TextInput`] is blurred,
+    /// depending on [`validate_on`](Self::validate_on). While the last
+    /// validation failed, the [`TextInput`] is drawn with the
+    /// [`Catalog::invalid`] style.
+    ///
+    /// Its initial value is not validated, so an empty form does not start
+    /// out covered in errors.
+    pub fn validate(
+        mut self,
+        validate: impl Fn(&str) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Sets when the value of the [`TextInput`] is
+    /// [`validate`](Self::validate)d.
+    ///
+    /// By default, it is validated on every change.
+    pub fn validate_on(mut self, validate_on: ValidateOn) -> Self {
+        self.validate_on = validate_on;
+        self
+    }
+
+    /// Sets the message that should be produced with the result of every
+    /// [`validate`](Self::validate) run, e.g. to store and show its error.
+    pub fn on_validation(
+        mut self,
+        on_validation: impl Fn(Result<(), String>) -> Message + 'a,
+    ) -> Self {
+        self.on_validation = Some(Box::new(on_validation));
+        self
+    }
+
     /// Sets the message that should be produced when some text is pasted into
     /// the [`TextInput`].
     pub fn on_paste(
@@ -256,6 +542,94 @@ where
         self
     }
 
+    /// Sets the tokens of the [`TextInput`], shown as chips before its text.
+    ///
+    /// This turns the [`TextInput`] into a tag input: the value is the token
+    /// being typed, which is edited through [`on_input`](Self::on_input) as
+    /// usual, while the tokens already entered are kept by the application.
+    /// See [`on_token_add`](Self::on_token_add) and
+    /// [`on_token_remove`](Self::on_token_remove).
+    ///
+    /// The chips take room from the text, so only a few short tokens fit.
+    pub fn tokens(mut self, tokens: Vec<String>) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
+    /// Sets the message that should be produced when a token is entered.
+    ///
+    /// A token is entered by typing a comma or pressing enter while the value
+    /// of the [`TextInput`] is not blank. The handler receives the trimmed
+    /// value, which the application should add to its
+    /// [`tokens`](Self::tokens) while clearing the value. The comma is never
+    /// typed into the value, and pressing enter to enter a token does not
+    /// produce the [`on_submit`](Self::on_submit) message.
+    pub fn on_token_add(
+        mut self,
+        on_token_add: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_token_add = Some(Box::new(on_token_add));
+        self
+    }
+
+    /// Sets the message that should be produced when a token is removed,
+    /// with its index in the [`tokens`](Self::tokens).
+    ///
+    /// A token is removed by clicking the "×" of its chip, or by pressing
+    /// backspace at the start of the value to remove the last one. Without
+    /// it, the chips have no "×".
+    pub fn on_token_remove(
+        mut self,
+        on_token_remove: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_token_remove = Some(Box::new(on_token_remove));
+        self
+    }
+
+    /// Sets the suggestions of the [`TextInput`], shown in a menu below it
+    /// while it is focused, e.g. the matches of what is being typed.
+    ///
+    /// The application is in charge of the suggestions: it should update
+    /// them as the value changes. They are only shown with
+    /// [`on_suggestion`](Self::on_suggestion).
+    ///
+    /// Typing keeps editing the value while the menu is open. The up and down
+    /// arrows highlight a suggestion, which enter picks instead of producing
+    /// the [`on_submit`](Self::on_submit) message. A suggestion can also be
+    /// clicked.
+    ///
+    /// The menu is hidden when the [`TextInput`] loses focus or is disabled.
+    /// Picking a suggestion or pressing escape hides it too, until the value
+    /// changes. It opens above the [`TextInput`] when there is more room
+    /// there than below it, e.g. near the bottom of the window.
+    pub fn suggestions(mut self, suggestions: Vec<String>) -> Self
+    where
+        Theme: menu::Catalog + 'a,
+    {
+        self.suggestions = suggestions;
+
+        if self.menu_class.is_none() {
+            self.menu_class = Some(Box::new(MenuClass::<Theme>(
+                <Theme as menu::Catalog>::default(),
+            )));
+        }
+
+        self
+    }
+
+    /// Sets the message that should be produced when one of the
+    /// [`suggestions`](Self::suggestions) is picked.
+    ///
+    /// The value is not changed by the [`TextInput`], so the application
+    /// will usually replace it with the suggestion.
+    pub fn on_suggestion(
+        mut self,
+        on_suggestion: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_suggestion = Some(Box::new(on_suggestion));
+        self
+    }
+
     /// Sets the width of the [`TextInput`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -302,6 +676,22 @@ where
         self
     }
 
+    /// Sets the style of the menu of [`suggestions`](Self::suggestions).
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        Theme: menu::Catalog + 'a,
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = Some(Box::new(MenuClass::<Theme>(
+            (Box::new(style) as menu::StyleFn<'a, Theme>).into(),
+        )));
+        self
+    }
+
     /// Sets the style class of the [`TextInput`].
     #[must_use]
     pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
@@ -309,6 +699,265 @@ where
         self
     }
 
+    /// Sets the style class of the menu of
+    /// [`suggestions`](Self::suggestions).
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self
+    where
+        Theme: menu::Catalog + 'a,
+    {
+        self.menu_class = Some(Box::new(MenuClass::<Theme>(class.into())));
+        self
+    }
+
+    /// Returns whether the [`TextInput`] is disabled.
+    fn is_disabled(&self) -> bool {
+        self.on_input.is_none()
+            && self.on_input_debounced.is_none()
+            && self.on_commit.is_none()
+    }
+
+    /// Returns how many graphemes can replace the selection of the
+    /// [`TextInput`] without exceeding its [`max_length`](Self::max_length).
+    fn room(&self, cursor: &Cursor) -> usize {
+        let Some(max_length) = self.max_length else {
+            return usize::MAX;
+        };
+
+        let selected = cursor
+            .selection(&self.value)
+            .map_or(0, |(start, end)| end - start);
+
+        max_length.saturating_sub(self.value.len() - selected)
+    }
+
+    /// Publishes an edit of the value of the [`TextInput`], or keeps the
+    /// value as a draft until it is committed if there is no
+    /// [`on_input`](Self::on_input).
+    fn input(
+        &self,
+        draft: &mut Option<Value>,
+        edited_at: &mut Option<Instant>,
+        value: String,
+        is_paste: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some((delay, _)) = &self.on_input_debounced {
+            let now = Instant::now();
+
+            *edited_at = Some(now);
+            shell.request_redraw_at(now + *delay);
+        }
+
+        let Some(on_input) = &self.on_input else {
+            *draft = Some(self.value.clone());
+            return;
+        };
+
+        let message = match &self.on_paste {
+            Some(on_paste) if is_paste => on_paste(value),
+            _ => on_input(value),
+        };
+
+        shell.publish(message);
+    }
+
+    /// Publishes the [`on_commit`](Self::on_commit) message with the value of
+    /// the [`TextInput`], discarding its draft.
+    fn commit(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.draft = None;
+
+        if let Some(on_commit) = &self.on_commit {
+            shell.publish(on_commit(self.value.to_string()));
+        }
+    }
+
+    /// Publishes the [`on_focus`](Self::on_focus) or
+    /// [`on_blur`](Self::on_blur) message if the focus of the [`TextInput`]
+    /// changed since it was last synced.
+    ///
+    /// Every focus change goes through here, whether it comes from the user
+    /// or from an operation, so all of them produce the same messages.
+    fn sync_focus(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let is_focused = state.is_focused.is_some();
+
+        if is_focused == state.was_focused {
+            return;
+        }
+
+        state.was_focused = is_focused;
+
+        let is_window_blurred = std::mem::take(&mut state.is_window_blurred);
+
+        if is_focused {
+            if self.select_on_focus {
+                state.cursor.select_all(&self.value);
+            }
+
+            if let Some(on_focus) = &self.on_focus {
+                shell.publish(on_focus(&self.value.to_string()));
+            }
+        } else {
+            if let Some(on_blur) = &self.on_blur
+                && !is_window_blurred
+            {
+                shell.publish(on_blur.clone());
+            }
+
+            self.commit(state, shell);
+        }
+    }
+
+    /// Publishes the value of the [`TextInput`] as a new token, unless it is
+    /// blank.
+    ///
+    /// Returns whether a token was added.
+    fn add_token(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let Some(on_token_add) = &self.on_token_add else {
+            return false;
+        };
+
+        let token = self.value.to_string().trim().to_owned();
+
+        if token.is_empty() {
+            return false;
+        }
+
+        state.draft = None;
+        shell.publish(on_token_add(token));
+
+        true
+    }
+
+    /// Returns the layouts of the token chips of the [`TextInput`].
+    fn chips<'b>(
+        &self,
+        layout: Layout<'b>,
+    ) -> impl Iterator<Item = Layout<'b>> + use<'b, 'a, Message, Theme, Renderer>
+    {
+        let children = layout.children().count();
+
+        layout
+            .children()
+            .skip(children.saturating_sub(self.tokens.len()))
+    }
+
+    /// Returns the layouts of the [`leading`](Self::leading) and
+    /// [`trailing`](Self::trailing) elements and of the "×" of
+    /// [`clearable`](Self::clearable), for the ones that are set.
+    fn slots<'b>(&self, layout: Layout<'b>) -> [Option<Layout<'b>>; 3] {
+        let mut children = layout.children().skip(
+            1 + usize::from(self.icon.is_some())
+                + usize::from(self.placeholder_element.is_some()),
+        );
+
+        [
+            self.leading.is_some(),
+            self.trailing.is_some(),
+            self.on_clear.is_some(),
+        ]
+        .map(|is_set| if is_set { children.next() } else { None })
+    }
+
+    /// Returns whether the menu of [`suggestions`](Self::suggestions) is
+    /// shown.
+    fn is_suggesting(&self, state: &State<Renderer::Paragraph>) -> bool {
+        self.on_suggestion.is_some()
+            && !self.suggestions.is_empty()
+            && !self.is_disabled()
+            && state.is_focused.is_some()
+            && state.dismissed_value.as_ref() != Some(&self.value)
+    }
+
+    /// Handles a key press on the menu of [`suggestions`](Self::suggestions),
+    /// returning whether it was used.
+    fn suggest(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        key: &keyboard::Key,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        if !self.is_suggesting(state) {
+            return false;
+        }
+
+        let count = self.suggestions.len();
+
+        match key.as_ref() {
+            keyboard::Key::Named(key::Named::ArrowDown) => {
+                state.hovered_suggestion = Some(
+                    state.hovered_suggestion.map_or(0, |i| (i + 1) % count),
+                );
+                state.menu.0.reveal();
+            }
+            keyboard::Key::Named(key::Named::ArrowUp) => {
+                state.hovered_suggestion =
+                    Some(state.hovered_suggestion.map_or(count - 1, |i| {
+                        (i.min(count) + count - 1) % count
+                    }));
+                state.menu.0.reveal();
+            }
+            keyboard::Key::Named(key::Named::Enter) => {
+                let Some(suggestion) = state
+                    .hovered_suggestion
+                    .and_then(|i| self.suggestions.get(i))
+                else {
+                    return false;
+                };
+
+                if let Some(on_suggestion) = &self.on_suggestion {
+                    shell.publish(on_suggestion(suggestion.clone()));
+                }
+
+                state.dismissed_value = Some(self.value.clone());
+            }
+            keyboard::Key::Named(key::Named::Escape) => {
+                state.dismissed_value = Some(self.value.clone());
+            }
+            _ => return false,
+        }
+
+        shell.request_redraw();
+
+        true
+    }
+
+    /// Returns whether the "×" of [`clearable`](Self::clearable) is shown.
+    fn is_clear_shown(&self) -> bool {
+        self.on_clear.is_some() && !self.is_disabled() && !self.value.is_empty()
+    }
+
+    /// Returns the index of the token whose "×" is under the cursor, if any.
+    fn token_remove_at(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        self.on_token_remove.as_ref()?;
+
+        let remove_width = state.token_remove.min_width();
+
+        self.chips(layout).position(|chip| {
+            cursor.is_over(chip_remove_bounds(chip.bounds(), remove_width))
+        })
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -320,6 +969,13 @@ where
         value: Option<&Value>,
     ) -> layout::Node {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if self.on_input.is_none()
+            && let Some(draft) = &state.draft
+        {
+            self.value = draft.clone();
+        }
+
         let value = value.unwrap_or(&self.value);
 
         let font = self.font.unwrap_or_else(|| renderer.default_font());
@@ -352,7 +1008,70 @@ where
             ..placeholder_text
         });
 
-        if let Some(icon) = &self.icon {
+        let slot_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(text_bounds.width, text_bounds.height + padding.y()),
+        );
+
+        let center = |node: layout::Node, x: f32| {
+            let y =
+                padding.top + (text_bounds.height - node.size().height) / 2.0;
+
+            node.move_to(Point::new(x, y))
+        };
+
+        let mut slot_trees = tree
+            .children
+            .iter_mut()
+            .skip(usize::from(self.placeholder_element.is_some()));
+
+        let mut left = padding.left;
+        let mut right = padding.left + text_bounds.width;
+
+        let leading_node = self.leading.as_mut().map(|leading| {
+            let node = leading.as_widget_mut().layout(
+                slot_trees.next().unwrap(),
+                renderer,
+                &slot_limits,
+            );
+
+            let width = node.size().width;
+            let node = center(node, left);
+
+            left += width + SLOT_SPACING;
+
+            node
+        });
+
+        let clear_node = self.on_clear.is_some().then(|| {
+            let _ = state.clear.update(Text {
+                content: "×",
+                ..placeholder_text
+            });
+
+            let width = state.clear.min_width() + CHIP_PADDING;
+
+            right -= width + SLOT_SPACING;
+
+            layout::Node::new(Size::new(width, text_bounds.height))
+                .move_to(Point::new(right + SLOT_SPACING, padding.top))
+        });
+
+        let trailing_node = self.trailing.as_mut().map(|trailing| {
+            let node = trailing.as_widget_mut().layout(
+                slot_trees.next().unwrap(),
+                renderer,
+                &slot_limits,
+            );
+
+            right -= node.size().width + SLOT_SPACING;
+
+            center(node, right + SLOT_SPACING)
+        });
+
+        let text_width = (right - left).max(0.0);
+
+        let (text_node, icon_node) = if let Some(icon) = &self.icon {
             let mut content = [0; 4];
 
             let icon_text = Text {
@@ -373,40 +1092,125 @@ where
 
             let (text_position, icon_position) = match icon.side {
                 Side::Left => (
-                    Point::new(
-                        padding.left + icon_width + icon.spacing,
-                        padding.top,
-                    ),
-                    Point::new(padding.left, padding.top),
+                    Point::new(left + icon_width + icon.spacing, padding.top),
+                    Point::new(left, padding.top),
                 ),
                 Side::Right => (
-                    Point::new(padding.left, padding.top),
-                    Point::new(
-                        padding.left + text_bounds.width - icon_width,
-                        padding.top,
-                    ),
+                    Point::new(left, padding.top),
+                    Point::new(left + text_width - icon_width, padding.top),
                 ),
             };
 
-            let text_node = layout::Node::new(
-                text_bounds - Size::new(icon_width + icon.spacing, 0.0),
-            )
+            let text_node = layout::Node::new(Size::new(
+                (text_width - icon_width - icon.spacing).max(0.0),
+                text_bounds.height,
+            ))
             .move_to(text_position);
 
             let icon_node =
                 layout::Node::new(Size::new(icon_width, text_bounds.height))
                     .move_to(icon_position);
 
-            layout::Node::with_children(
-                text_bounds.expand(padding),
-                vec![text_node, icon_node],
-            )
+            (text_node, Some(icon_node))
         } else {
-            let text = layout::Node::new(text_bounds)
-                .move_to(Point::new(padding.left, padding.top));
+            let text =
+                layout::Node::new(Size::new(text_width, text_bounds.height))
+                    .move_to(Point::new(left, padding.top));
 
-            layout::Node::with_children(text_bounds.expand(padding), vec![text])
-        }
+            (text, None)
+        };
+
+        let (text_node, chip_nodes) = if self.tokens.is_empty() {
+            (text_node, Vec::new())
+        } else {
+            let _ = state.token_remove.update(Text {
+                content: "×",
+                ..placeholder_text
+            });
+
+            let remove_width = if self.on_token_remove.is_some() {
+                state.token_remove.min_width() + CHIP_PADDING
+            } else {
+                0.0
+            };
+
+            state.tokens.truncate(self.tokens.len());
+
+            let text_bounds = text_node.bounds();
+            let mut x = text_bounds.x;
+
+            let chip_nodes = self
+                .tokens
+                .iter()
+                .enumerate()
+                .map(|(i, token)| {
+                    let text = Text {
+                        content: token.as_str(),
+                        ..placeholder_text
+                    };
+
+                    if i == state.tokens.len() {
+                        state.tokens.push(paragraph::Plain::default());
+                    }
+
+                    let _ = state.tokens[i].update(text);
+
+                    let width = state.tokens[i].min_width()
+                        + remove_width
+                        + CHIP_PADDING * 2.0;
+
+                    let node =
+                        layout::Node::new(Size::new(width, text_bounds.height))
+                            .move_to(Point::new(x, text_bounds.y));
+
+                    x += width + CHIP_SPACING;
+
+                    node
+                })
+                .collect();
+
+            let chips_width = x - text_bounds.x;
+
+            let text_node = layout::Node::new(Size::new(
+                (text_bounds.width - chips_width).max(0.0),
+                text_bounds.height,
+            ))
+            .move_to(Point::new(x, text_bounds.y));
+
+            (text_node, chip_nodes)
+        };
+
+        let placeholder_node =
+            self.placeholder_element.as_mut().map(|element| {
+                let text_bounds = text_node.bounds();
+
+                let node = element.as_widget_mut().layout(
+                    &mut tree.children[0],
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, text_bounds.size()),
+                );
+
+                let y = text_bounds.y
+                    + (text_bounds.height - node.size().height).max(0.0) / 2.0;
+
+                node.move_to(Point::new(text_bounds.x, y))
+            });
+
+        layout::Node::with_children(
+            text_bounds.expand(padding),
+            [
+                Some(text_node),
+                icon_node,
+                placeholder_node,
+                leading_node,
+                trailing_node,
+                clear_node,
+            ]
+            .into_iter()
+            .flatten()
+            .chain(chip_nodes)
+            .collect(),
+        )
     }
 
     fn input_method<'b>(
@@ -417,6 +1221,7 @@ where
     ) -> InputMethod<&'b str> {
         let Some(Focus {
             is_window_focused: true,
+            scroll_offset,
             ..
         }) = &state.is_focused
         else {
@@ -434,8 +1239,7 @@ where
         };
 
         let text = state.value.raw();
-        let (cursor_x, scroll_offset) =
-            measure_cursor_and_scroll_offset(text, text_bounds, caret_index);
+        let cursor_x = measure_cursor(text, caret_index);
 
         let alignment_offset = alignment_offset(
             text_bounds.width,
@@ -443,7 +1247,7 @@ where
             self.alignment,
         );
 
-        let x = (text_bounds.x + cursor_x).floor() - scroll_offset
+        let x = (text_bounds.x + cursor_x).floor() - *scroll_offset
             + alignment_offset;
 
         InputMethod::Enabled {
@@ -471,13 +1275,13 @@ where
         renderer: &mut Renderer,
         theme: &Theme,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         value: Option<&Value>,
         viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let value = value.unwrap_or(&self.value);
-        let is_disabled = self.on_input.is_none();
+        let is_disabled = self.is_disabled();
 
         let secure_value = self.is_secure.then(|| value.secure());
         let value = secure_value.as_ref().unwrap_or(value);
@@ -487,8 +1291,13 @@ where
         let mut children_layout = layout.children();
         let text_bounds = children_layout.next().unwrap().bounds();
 
-        let style = theme
-            .style(&self.class, self.last_status.unwrap_or(Status::Disabled));
+        let status = self.last_status.unwrap_or(Status::Disabled);
+
+        let style = if self.validate.is_some() && state.validation.is_invalid {
+            theme.invalid(&self.class, status)
+        } else {
+            theme.style(&self.class, status)
+        };
 
         renderer.fill_quad(
             renderer::Quad {
@@ -516,8 +1325,122 @@ where
             );
         }
 
+        for (chip, paragraph) in self.chips(layout).zip(&state.tokens) {
+            let chip = chip.bounds();
+            let label = paragraph.raw();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: chip,
+                    border: border::rounded(chip.height / 2.0),
+                    ..renderer::Quad::default()
+                },
+                style.selection,
+            );
+
+            renderer.fill_paragraph(
+                label,
+                Rectangle {
+                    x: chip.x + CHIP_PADDING,
+                    ..chip
+                }
+                .anchor(
+                    label.min_bounds(),
+                    Alignment::Start,
+                    Alignment::Center,
+                ),
+                style.value,
+                *viewport,
+            );
+
+            if self.on_token_remove.is_some() {
+                let remove = state.token_remove.raw();
+
+                renderer.fill_paragraph(
+                    remove,
+                    chip_remove_bounds(chip, remove.min_width()).anchor(
+                        remove.min_bounds(),
+                        Alignment::Center,
+                        Alignment::Center,
+                    ),
+                    style.value,
+                    *viewport,
+                );
+            }
+        }
+
+        let [leading, trailing, clear] = self.slots(layout);
+        let offset = usize::from(self.placeholder_element.is_some());
+
+        for ((element, tree), layout) in self
+            .leading
+            .iter()
+            .chain(&self.trailing)
+            .zip(&tree.children[offset..])
+            .zip(leading.into_iter().chain(trailing))
+        {
+            element.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.icon,
+                },
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+
+        if self.is_clear_shown()
+            && let Some(clear) = clear
+        {
+            let paragraph = state.clear.raw();
+
+            renderer.fill_paragraph(
+                paragraph,
+                clear.bounds().anchor(
+                    paragraph.min_bounds(),
+                    Alignment::Center,
+                    Alignment::Center,
+                ),
+                style.icon,
+                *viewport,
+            );
+        }
+
         let text = value.to_string();
 
+        let is_empty = text.is_empty()
+            && state
+                .preedit
+                .as_ref()
+                .map(|preedit| preedit.content.is_empty())
+                .unwrap_or(true);
+
+        if is_empty
+            && state.is_focused.is_none()
+            && let Some(element) = &self.placeholder_element
+        {
+            if let Some(placeholder_layout) = children_layout.next() {
+                renderer.with_layer(text_bounds, |renderer| {
+                    element.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.placeholder,
+                        },
+                        placeholder_layout,
+                        cursor,
+                        viewport,
+                    );
+                });
+            }
+
+            return;
+        }
+
         let (cursor, offset, is_selecting) = if let Some(focus) = state
             .is_focused
             .as_ref()
@@ -525,12 +1448,8 @@ where
         {
             match state.cursor.state(value) {
                 cursor::State::Index(position) => {
-                    let (text_value_width, offset) =
-                        measure_cursor_and_scroll_offset(
-                            state.value.raw(),
-                            text_bounds,
-                            position,
-                        );
+                    let text_value_width =
+                        measure_cursor(state.value.raw(), position);
 
                     let is_cursor_visible = !is_disabled
                         && ((focus.now - focus.updated_at).as_millis()
@@ -555,25 +1474,15 @@ where
                         None
                     };
 
-                    (cursor, offset, false)
+                    (cursor, focus.scroll_offset, false)
                 }
                 cursor::State::Selection { start, end } => {
                     let left = start.min(end);
                     let right = end.max(start);
 
-                    let (left_position, left_offset) =
-                        measure_cursor_and_scroll_offset(
-                            state.value.raw(),
-                            text_bounds,
-                            left,
-                        );
-
-                    let (right_position, right_offset) =
-                        measure_cursor_and_scroll_offset(
-                            state.value.raw(),
-                            text_bounds,
-                            right,
-                        );
+                    let left_position = measure_cursor(state.value.raw(), left);
+                    let right_position =
+                        measure_cursor(state.value.raw(), right);
 
                     let width = right_position - left_position;
 
@@ -590,11 +1499,7 @@ where
                             },
                             style.selection,
                         )),
-                        if end == right {
-                            right_offset
-                        } else {
-                            left_offset
-                        },
+                        focus.scroll_offset,
                         true,
                     )
                 }
@@ -604,13 +1509,7 @@ where
         };
 
         let draw = |renderer: &mut Renderer, viewport| {
-            let paragraph = if text.is_empty()
-                && state
-                    .preedit
-                    .as_ref()
-                    .map(|preedit| preedit.content.is_empty())
-                    .unwrap_or(true)
-            {
+            let paragraph = if is_empty {
                 state.placeholder.raw()
             } else {
                 state.value.raw()
@@ -673,11 +1572,29 @@ where
         tree::State::new(State::<Renderer::Paragraph>::new())
     }
 
+    fn children(&self) -> Vec<Tree> {
+        self.placeholder_element
+            .iter()
+            .chain(&self.leading)
+            .chain(&self.trailing)
+            .map(Tree::new)
+            .collect()
+    }
+
     fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = self
+            .placeholder_element
+            .iter()
+            .chain(&self.leading)
+            .chain(&self.trailing)
+            .collect();
+
+        tree.diff_children(&children);
+
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
         // Stop pasting if input becomes disabled
-        if self.on_input.is_none() {
+        if self.is_disabled() {
             state.is_pasting = None;
         }
     }
@@ -702,13 +1619,44 @@ where
         &mut self,
         tree: &mut Tree,
         layout: Layout<'_>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
         operation.text_input(self.id.as_ref(), layout.bounds(), state);
+        operation.custom(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut TabIndex(self.tab_index),
+        );
         operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.custom(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut WidgetState::TextInput {
+                cursor: match state.cursor.state(&self.value) {
+                    cursor::State::Index(index) => index,
+                    cursor::State::Selection { end, .. } => end,
+                },
+                selection: state.cursor.selection(&self.value),
+            },
+        );
+
+        let [leading, trailing, _] = self.slots(layout);
+        let offset = usize::from(self.placeholder_element.is_some());
+
+        for ((element, tree), layout) in self
+            .leading
+            .iter_mut()
+            .chain(&mut self.trailing)
+            .zip(&mut tree.children[offset..])
+            .zip(leading.into_iter().chain(trailing))
+        {
+            element
+                .as_widget_mut()
+                .operate(tree, layout, renderer, operation);
+        }
     }
 
     fn update(
@@ -720,7 +1668,7 @@ where
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let update_cache = |state, value| {
             replace_paragraph(
@@ -734,56 +1682,143 @@ where
             );
         };
 
-        // Detect focus changes from operations (e.g., Tab key)
-        {
+        if let Some(validate) = &self.validate {
             let state = state::<Renderer>(tree);
             let is_focused = state.is_focused.is_some();
-            if is_focused != state.was_focused {
-                if is_focused {
-                    if let Some(on_focus) = &self.on_focus {
-                        shell.publish(on_focus.clone());
-                    }
-                } else if let Some(on_blur) = &self.on_blur {
-                    shell.publish(on_blur.clone());
+            let validation = &mut state.validation;
+
+            let is_changed = validation
+                .value
+                .as_ref()
+                .is_some_and(|value| *value != self.value);
+            let is_blurred = validation.is_focused && !is_focused;
+
+            validation.value = Some(self.value.clone());
+            validation.is_focused = is_focused;
+
+            let should_validate = match self.validate_on {
+                ValidateOn::Input => is_changed,
+                ValidateOn::Blur => is_blurred,
+                ValidateOn::Both => is_changed || is_blurred,
+            };
+
+            if should_validate {
+                let result = validate(&self.value.to_string());
+
+                if validation.is_invalid != result.is_err() {
+                    validation.is_invalid = result.is_err();
+                    shell.request_redraw();
+                }
+
+                if let Some(on_validation) = &self.on_validation {
+                    shell.publish(on_validation(result));
+                }
+            }
+        }
+
+        // Detect focus changes from operations (e.g., Tab key), which cannot
+        // publish messages themselves
+        self.sync_focus(state::<Renderer>(tree), shell);
+
+        if let Some((delay, on_input)) = &self.on_input_debounced {
+            let state = state::<Renderer>(tree);
+
+            let now = match event {
+                Event::Window(window::Event::RedrawRequested(now)) => *now,
+                _ => Instant::now(),
+            };
+
+            if let Some(edited_at) = state.edited_at {
+                if now >= edited_at + *delay {
+                    state.edited_at = None;
+                    state.draft = None;
+
+                    shell.publish(on_input(self.value.to_string()));
+                } else {
+                    shell.request_redraw_at(edited_at + *delay);
                 }
-                state.was_focused = is_focused;
             }
         }
 
+        // A highlighted suggestion is stale once the value changes
+        if self.on_suggestion.is_some() {
+            let state = state::<Renderer>(tree);
+
+            if state.suggested_value.as_ref() != Some(&self.value) {
+                state.suggested_value = Some(self.value.clone());
+                state.hovered_suggestion = None;
+            }
+        }
+
+        // The leading and trailing elements get events first, so a button
+        // there does not focus the input when clicked
+        let [leading, trailing, clear] = self.slots(layout);
+        let offset = usize::from(self.placeholder_element.is_some());
+
+        for ((element, tree), layout) in self
+            .leading
+            .iter_mut()
+            .chain(&mut self.trailing)
+            .zip(&mut tree.children[offset..])
+            .zip(leading.into_iter().chain(trailing))
+        {
+            element.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
         match &event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let state = state::<Renderer>(tree);
+
+                if !self.is_disabled()
+                    && let Some(on_token_remove) = &self.on_token_remove
+                    && let Some(index) =
+                        self.token_remove_at(state, layout, cursor)
+                {
+                    shell.publish(on_token_remove(index));
+                    shell.capture_event();
+                    return;
+                }
+
+                if self.is_clear_shown()
+                    && let Some(on_clear) = &self.on_clear
+                    && clear.is_some_and(|clear| cursor.is_over(clear.bounds()))
+                {
+                    shell.publish(on_clear.clone());
+                    shell.capture_event();
+                    return;
+                }
+
                 let cursor_before = state.cursor;
-                let was_focused = state.is_focused.is_some();
 
                 let click_position = cursor.position_over(layout.bounds());
 
                 state.is_focused = if click_position.is_some() {
                     let now = Instant::now();
 
-                    if !was_focused {
-                        if let Some(on_focus) = &self.on_focus {
-                            shell.publish(on_focus.clone());
-                        }
-                    }
-
                     Some(Focus {
                         updated_at: now,
                         now,
                         is_window_focused: true,
+                        scroll_offset: state
+                            .is_focused
+                            .as_ref()
+                            .map_or(0.0, |focus| focus.scroll_offset),
                     })
                 } else {
-                    if was_focused {
-                        if let Some(on_blur) = &self.on_blur {
-                            shell.publish(on_blur.clone());
-                        }
-                    }
-
                     None
                 };
 
-                state.was_focused = state.is_focused.is_some();
+                let was_focused = state.was_focused;
+
+                self.sync_focus(state, shell);
 
                 if let Some(cursor_position) = click_position {
                     let text_layout = layout.children().next().unwrap();
@@ -807,6 +1842,12 @@ where
                     );
 
                     match click.kind() {
+                        click::Kind::Single
+                            if self.select_on_focus && !was_focused =>
+                        {
+                            // The value was selected when focused
+                            state.is_dragging = None;
+                        }
                         click::Kind::Single => {
                             let position = if target > 0.0 {
                                 let value = if self.is_secure {
@@ -843,7 +1884,7 @@ where
 
                                 state.is_dragging = None;
                             } else {
-                                let position = find_cursor_position(
+                                let grapheme = find_grapheme(
                                     text_layout.bounds(),
                                     &self.value,
                                     state,
@@ -851,14 +1892,11 @@ where
                                 )
                                 .unwrap_or(0);
 
-                                state.cursor.select_range(
-                                    self.value.previous_start_of_word(position),
-                                    self.value.next_end_of_word(position),
-                                );
+                                let (start, end) = self.value.word_at(grapheme);
 
-                                state.is_dragging = Some(Drag::SelectWords {
-                                    anchor: position,
-                                });
+                                state.cursor.select_range(start, end);
+                                state.is_dragging =
+                                    Some(Drag::SelectWords { start, end });
                             }
                         }
                         click::Kind::Triple => {
@@ -923,17 +1961,28 @@ where
                                 position,
                             );
                         }
-                        Drag::SelectWords { anchor } => {
-                            if position < *anchor {
-                                state.cursor.select_range(
-                                    self.value.previous_start_of_word(position),
-                                    self.value.next_end_of_word(*anchor),
-                                );
+                        Drag::SelectWords { start, end } => {
+                            let (start, end) = (*start, *end);
+
+                            let grapheme = find_grapheme(
+                                text_layout.bounds(),
+                                &value,
+                                state,
+                                target,
+                            )
+                            .unwrap_or(0);
+
+                            let (word_start, word_end) =
+                                self.value.word_at(grapheme);
+
+                            // Extend the selection by whole words, keeping
+                            // the double-clicked word selected
+                            if word_start < start {
+                                state.cursor.select_range(end, word_start);
                             } else {
-                                state.cursor.select_range(
-                                    self.value.previous_start_of_word(*anchor),
-                                    self.value.next_end_of_word(position),
-                                );
+                                state
+                                    .cursor
+                                    .select_range(start, word_end.max(end));
                             }
                         }
                     }
@@ -954,6 +2003,11 @@ where
             }) => {
                 let state = state::<Renderer>(tree);
 
+                if self.suggest(state, key, shell) {
+                    shell.capture_event();
+                    return;
+                }
+
                 if let Some(focus) = &mut state.is_focused {
                     let modifiers = state.keyboard_modifiers;
 
@@ -978,9 +2032,9 @@ where
                             if state.keyboard_modifiers.command()
                                 && !self.is_secure =>
                         {
-                            let Some(on_input) = &self.on_input else {
+                            if self.is_disabled() {
                                 return;
-                            };
+                            }
 
                             if let Some((start, end)) =
                                 state.cursor.selection(&self.value)
@@ -995,8 +2049,14 @@ where
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
 
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            let contents = editor.contents();
+                            self.input(
+                                &mut state.draft,
+                                &mut state.edited_at,
+                                contents,
+                                false,
+                                shell,
+                            );
                             shell.capture_event();
 
                             focus.updated_at = Instant::now();
@@ -1007,9 +2067,9 @@ where
                             if state.keyboard_modifiers.command()
                                 && !state.keyboard_modifiers.alt() =>
                         {
-                            let Some(on_input) = &self.on_input else {
+                            if self.is_disabled() {
                                 return;
-                            };
+                            }
 
                             let content = match state.is_pasting.take() {
                                 Some(content) => content,
@@ -1025,16 +2085,20 @@ where
                                 }
                             };
 
+                            let room = self.room(&state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
-                            editor.paste(content.clone());
-
-                            let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
-                            } else {
-                                (on_input)(editor.contents())
-                            };
-                            shell.publish(message);
+                            editor.paste(content.select(0, room));
+
+                            let contents = editor.contents();
+                            self.input(
+                                &mut state.draft,
+                                &mut state.edited_at,
+                                contents,
+                                true,
+                                shell,
+                            );
                             shell.capture_event();
 
                             state.is_pasting = Some(content);
@@ -1062,22 +2126,41 @@ where
                     }
 
                     if let Some(text) = text {
-                        let Some(on_input) = &self.on_input else {
+                        if self.is_disabled() {
                             return;
-                        };
+                        }
 
                         state.is_pasting = None;
 
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
+                            if c == TOKEN_DELIMITER
+                                && self.on_token_add.is_some()
+                            {
+                                let _ = self.add_token(state, shell);
+                                shell.capture_event();
+                                return;
+                            }
+
+                            if self.room(&state.cursor) == 0 {
+                                shell.capture_event();
+                                return;
+                            }
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
                             editor.insert(c);
 
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            let contents = editor.contents();
+                            self.input(
+                                &mut state.draft,
+                                &mut state.edited_at,
+                                contents,
+                                false,
+                                shell,
+                            );
                             shell.capture_event();
 
                             focus.updated_at = Instant::now();
@@ -1091,15 +2174,38 @@ where
 
                     match key.as_ref() {
                         keyboard::Key::Named(key::Named::Enter) => {
-                            if let Some(on_submit) = self.on_submit.clone() {
+                            if self.add_token(state, shell) {
+                                shell.capture_event();
+                                return;
+                            }
+
+                            if self.commit_on_enter {
+                                self.commit(state, shell);
+                            }
+
+                            if let Some(on_submit) = self.on_submit.clone()
+                                && !self.is_disabled()
+                            {
                                 shell.publish(on_submit);
                                 shell.capture_event();
                             }
                         }
                         keyboard::Key::Named(key::Named::Backspace) => {
-                            let Some(on_input) = &self.on_input else {
+                            if self.is_disabled() {
                                 return;
-                            };
+                            }
+
+                            // Backspace at the start removes the last token
+                            if let Some(on_token_remove) = &self.on_token_remove
+                                && let Some(last) =
+                                    self.tokens.len().checked_sub(1)
+                                && state.cursor.selection(&self.value).is_none()
+                                && state.cursor.start(&self.value) == 0
+                            {
+                                shell.publish(on_token_remove(last));
+                                shell.capture_event();
+                                return;
+                            }
 
                             if state.cursor.selection(&self.value).is_none() {
                                 if (self.is_secure && modifiers.jump())
@@ -1120,17 +2226,23 @@ where
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.backspace();
 
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            let contents = editor.contents();
+                            self.input(
+                                &mut state.draft,
+                                &mut state.edited_at,
+                                contents,
+                                false,
+                                shell,
+                            );
                             shell.capture_event();
 
                             focus.updated_at = Instant::now();
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Delete) => {
-                            let Some(on_input) = &self.on_input else {
+                            if self.is_disabled() {
                                 return;
-                            };
+                            }
 
                             if state.cursor.selection(&self.value).is_none() {
                                 if (self.is_secure && modifiers.jump())
@@ -1151,8 +2263,14 @@ where
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
 
-                            let message = (on_input)(editor.contents());
-                            shell.publish(message);
+                            let contents = editor.contents();
+                            self.input(
+                                &mut state.draft,
+                                &mut state.edited_at,
+                                contents,
+                                false,
+                                shell,
+                            );
                             shell.capture_event();
 
                             focus.updated_at = Instant::now();
@@ -1282,9 +2400,7 @@ where
                             state.keyboard_modifiers =
                                 keyboard::Modifiers::default();
 
-                            if let Some(on_blur) = &self.on_blur {
-                                shell.publish(on_blur.clone());
-                            }
+                            self.sync_focus(state, shell);
 
                             shell.capture_event();
                         }
@@ -1337,19 +2453,27 @@ where
                     let state = state::<Renderer>(tree);
 
                     if let Some(focus) = &mut state.is_focused {
-                        let Some(on_input) = &self.on_input else {
+                        if self.is_disabled() {
                             return;
-                        };
+                        }
+
+                        let room = self.room(&state.cursor);
 
                         let mut editor =
                             Editor::new(&mut self.value, &mut state.cursor);
-                        editor.paste(Value::new(text));
+                        editor.paste(Value::new(text).select(0, room));
 
                         focus.updated_at = Instant::now();
                         state.is_pasting = None;
 
-                        let message = (on_input)(editor.contents());
-                        shell.publish(message);
+                        let contents = editor.contents();
+                        self.input(
+                            &mut state.draft,
+                            &mut state.edited_at,
+                            contents,
+                            false,
+                            shell,
+                        );
                         shell.capture_event();
 
                         update_cache(state, &self.value);
@@ -1361,6 +2485,14 @@ where
 
                 if let Some(focus) = &mut state.is_focused {
                     focus.is_window_focused = false;
+
+                    if self.blur_with_window && !state.is_window_blurred {
+                        state.is_window_blurred = true;
+
+                        if let Some(on_blur) = &self.on_blur {
+                            shell.publish(on_blur.clone());
+                        }
+                    }
                 }
             }
             Event::Window(window::Event::Focused) => {
@@ -1372,6 +2504,12 @@ where
 
                     shell.request_redraw();
                 }
+
+                if std::mem::take(&mut state.is_window_blurred)
+                    && let Some(on_focus) = &self.on_focus
+                {
+                    shell.publish(on_focus(&self.value.to_string()));
+                }
             }
             Event::Window(window::Event::RedrawRequested(now)) => {
                 let state = state::<Renderer>(tree);
@@ -1396,6 +2534,24 @@ where
                         );
                     }
 
+                    // Follow the text cursor, which may have moved since the
+                    // last frame
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+                    let position = match state.cursor.state(&self.value) {
+                        cursor::State::Index(position) => position,
+                        cursor::State::Selection { end, .. } => end,
+                    };
+
+                    let paragraph = state.value.raw();
+
+                    focus.scroll_offset = scroll_offset(
+                        measure_cursor(paragraph, position),
+                        paragraph.min_width(),
+                        text_bounds.width,
+                        focus.scroll_offset,
+                    );
+
                     shell.request_input_method(&self.input_method(
                         state,
                         layout,
@@ -1407,7 +2563,7 @@ where
         }
 
         let state = state::<Renderer>(tree);
-        let is_disabled = self.on_input.is_none();
+        let is_disabled = self.is_disabled();
 
         let status = if is_disabled {
             Status::Disabled
@@ -1446,15 +2602,45 @@ where
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        if cursor.is_over(layout.bounds()) {
-            if self.on_input.is_none() {
+        let [leading, trailing, clear] = self.slots(layout);
+        let offset = usize::from(self.placeholder_element.is_some());
+
+        let slot_interaction = self
+            .leading
+            .iter()
+            .chain(&self.trailing)
+            .zip(&tree.children[offset..])
+            .zip(leading.into_iter().chain(trailing))
+            .map(|((element, tree), layout)| {
+                element
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .find(|interaction| *interaction != mouse::Interaction::default());
+
+        if let Some(interaction) = slot_interaction {
+            interaction
+        } else if cursor.is_over(layout.bounds()) {
+            if self.is_disabled() {
                 mouse::Interaction::Idle
+            } else if self
+                .token_remove_at(
+                    tree.state.downcast_ref::<State<Renderer::Paragraph>>(),
+                    layout,
+                    cursor,
+                )
+                .is_some()
+                || (self.is_clear_shown()
+                    && clear
+                        .is_some_and(|clear| cursor.is_over(clear.bounds())))
+            {
+                mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::Text
             }
@@ -1462,6 +2648,45 @@ where
             mouse::Interaction::default()
         }
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if !self.is_suggesting(state) {
+            return None;
+        }
+
+        let on_suggestion = self.on_suggestion.as_ref()?;
+        let menu_class = self.menu_class.as_ref()?;
+        let value = &self.value;
+        let bounds = layout.bounds();
+
+        Some(menu_class.overlay(SuggestionsMenu {
+            state: &mut state.menu.0,
+            suggestions: &self.suggestions,
+            hovered: &mut state.hovered_suggestion,
+            on_select: Box::new(|suggestion| {
+                state.dismissed_value = Some(value.clone());
+
+                on_suggestion(suggestion)
+            }),
+            width: bounds.width,
+            padding: self.padding,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            line_height: self.line_height,
+            size: self.size,
+            position: layout.position() + translation,
+            viewport: *viewport,
+            target_height: bounds.height,
+        }))
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<TextInput<'a, Message, Theme, Renderer>>
@@ -1508,15 +2733,118 @@ pub struct State<P: text::Paragraph> {
     value: paragraph::Plain<P>,
     placeholder: paragraph::Plain<P>,
     icon: paragraph::Plain<P>,
+    tokens: Vec<paragraph::Plain<P>>,
+    token_remove: paragraph::Plain<P>,
+    clear: paragraph::Plain<P>,
     is_focused: Option<Focus>,
     was_focused: bool,
+    is_window_blurred: bool,
     is_dragging: Option<Drag>,
     is_pasting: Option<Value>,
     preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
-    // TODO: Add stateful horizontal scrolling offset
+    validation: Validation,
+    draft: Option<Value>,
+    edited_at: Option<Instant>,
+    menu: SuggestionMenu,
+    hovered_suggestion: Option<usize>,
+    suggested_value: Option<Value>,
+    dismissed_value: Option<Value>,
+}
+
+/// The [`menu::State`] of the suggestions of a [`TextInput`], which starts
+/// over when cloned.
+#[derive(Debug, Default)]
+struct SuggestionMenu(menu::State);
+
+impl Clone for SuggestionMenu {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+/// The style class of the menu of [`suggestions`](TextInput::suggestions).
+///
+/// It builds the menu itself, so only the methods setting up the
+/// suggestions require the theme to be a [`menu::Catalog`].
+trait SuggestionsClass<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Produces the overlay of the given menu, styled with this class.
+    fn overlay<'b>(
+        &'b self,
+        menu: SuggestionsMenu<'b, Message, Renderer>,
+    ) -> overlay::Element<'b, Message, Theme, Renderer>
+    where
+        'a: 'b,
+        Message: 'b,
+        Renderer: 'b;
+}
+
+/// A [`menu::Catalog`] class of the menu of
+/// [`suggestions`](TextInput::suggestions).
+struct MenuClass<'a, Theme: menu::Catalog>(<Theme as menu::Catalog>::Class<'a>);
+
+impl<'a, Message, Theme, Renderer>
+    SuggestionsClass<'a, Message, Theme, Renderer> for MenuClass<'a, Theme>
+where
+    Theme: menu::Catalog + 'a,
+    Renderer: text::Renderer,
+{
+    fn overlay<'b>(
+        &'b self,
+        menu: SuggestionsMenu<'b, Message, Renderer>,
+    ) -> overlay::Element<'b, Message, Theme, Renderer>
+    where
+        'a: 'b,
+        Message: 'b,
+        Renderer: 'b,
+    {
+        let mut list = Menu::new(
+            menu.state,
+            menu.suggestions,
+            menu.hovered,
+            menu.on_select,
+            None,
+            None,
+            &self.0,
+        )
+        .width(menu.width)
+        .padding(menu.padding)
+        .font(menu.font)
+        .text_line_height(menu.line_height);
+
+        if let Some(size) = menu.size {
+            list = list.text_size(size);
+        }
+
+        list.overlay(
+            menu.position,
+            menu.viewport,
+            menu.target_height,
+            Length::Shrink,
+        )
+    }
+}
+
+/// The menu of [`suggestions`](TextInput::suggestions) of a [`TextInput`],
+/// waiting for its [`SuggestionsClass`].
+struct SuggestionsMenu<'b, Message, Renderer: text::Renderer> {
+    state: &'b mut menu::State,
+    suggestions: &'b [String],
+    hovered: &'b mut Option<usize>,
+    on_select: Box<dyn FnMut(String) -> Message + 'b>,
+    width: f32,
+    padding: Padding,
+    font: Renderer::Font,
+    line_height: text::LineHeight,
+    size: Option<Pixels>,
+    position: Point,
+    viewport: Rectangle,
+    target_height: f32,
 }
 
 fn state<Renderer: text::Renderer>(
@@ -1530,12 +2858,20 @@ struct Focus {
     updated_at: Instant,
     now: Instant,
     is_window_focused: bool,
+    scroll_offset: f32,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Validation {
+    value: Option<Value>,
+    is_focused: bool,
+    is_invalid: bool,
 }
 
 #[derive(Debug, Clone)]
 enum Drag {
     Select,
-    SelectWords { anchor: usize },
+    SelectWords { start: usize, end: usize },
 }
 
 impl<P: text::Paragraph> State<P> {
@@ -1562,6 +2898,7 @@ impl<P: text::Paragraph> State<P> {
             updated_at: now,
             now,
             is_window_focused: true,
+            scroll_offset: 0.0,
         });
 
         self.move_cursor_to_end();
@@ -1642,43 +2979,42 @@ impl<P: text::Paragraph> operation::TextInput for State<P> {
     }
 }
 
-fn offset<P: text::Paragraph>(
-    text_bounds: Rectangle,
-    value: &Value,
-    state: &State<P>,
-) -> f32 {
-    if state.is_focused() {
-        let cursor = state.cursor();
-
-        let focus_position = match cursor.state(value) {
-            cursor::State::Index(i) => i,
-            cursor::State::Selection { end, .. } => end,
-        };
-
-        let (_, offset) = measure_cursor_and_scroll_offset(
-            state.value.raw(),
-            text_bounds,
-            focus_position,
-        );
-
-        offset
-    } else {
-        0.0
-    }
+fn offset<P: text::Paragraph>(state: &State<P>) -> f32 {
+    state
+        .is_focused
+        .as_ref()
+        .map_or(0.0, |focus| focus.scroll_offset)
 }
 
-fn measure_cursor_and_scroll_offset(
+fn measure_cursor(
     paragraph: &impl text::Paragraph,
-    text_bounds: Rectangle,
     cursor_index: usize,
-) -> (f32, f32) {
-    let grapheme_position = paragraph
+) -> f32 {
+    paragraph
         .grapheme_position(0, cursor_index)
-        .unwrap_or(Point::ORIGIN);
+        .unwrap_or(Point::ORIGIN)
+        .x
+}
+
+/// Computes the horizontal scroll that keeps the text cursor at `cursor_x`
+/// visible in the given `visible_width`, moving the current scroll as little
+/// as possible.
+///
+/// The text never scrolls further than needed to show its end.
+fn scroll_offset(
+    cursor_x: f32,
+    text_width: f32,
+    visible_width: f32,
+    current: f32,
+) -> f32 {
+    const MARGIN: f32 = 5.0;
 
-    let offset = ((grapheme_position.x + 5.0) - text_bounds.width).max(0.0);
+    let max = (text_width + MARGIN - visible_width).max(0.0);
 
-    (grapheme_position.x, offset)
+    current
+        .max(cursor_x + MARGIN - visible_width)
+        .min(cursor_x)
+        .clamp(0.0, max)
 }
 
 /// Computes the position of the text cursor at the given X coordinate of
@@ -1689,7 +3025,7 @@ fn find_cursor_position<P: text::Paragraph>(
     state: &State<P>,
     x: f32,
 ) -> Option<usize> {
-    let offset = offset(text_bounds, value, state);
+    let offset = offset(state);
     let value = value.to_string();
 
     let char_offset = state
@@ -1707,6 +3043,28 @@ fn find_cursor_position<P: text::Paragraph>(
     )
 }
 
+/// Computes the index of the grapheme at the given X coordinate of a
+/// [`TextInput`].
+///
+/// Unlike [`find_cursor_position`], which finds the closest position between
+/// graphemes, this finds the grapheme the coordinate falls on.
+fn find_grapheme<P: text::Paragraph>(
+    text_bounds: Rectangle,
+    value: &Value,
+    state: &State<P>,
+    x: f32,
+) -> Option<usize> {
+    let position = find_cursor_position(text_bounds, value, state, x)?;
+    let position_x =
+        measure_cursor(state.value.raw(), position) - offset(state);
+
+    Some(if position > 0 && x < position_x {
+        position - 1
+    } else {
+        position
+    })
+}
+
 fn replace_paragraph<Renderer>(
     renderer: &Renderer,
     state: &mut State<Renderer::Paragraph>,
@@ -1739,6 +3097,30 @@ fn replace_paragraph<Renderer>(
 
 const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
 
+/// The character that enters a token in a [`TextInput`] when typed.
+const TOKEN_DELIMITER: char = ',';
+
+/// The horizontal padding inside a token chip of a [`TextInput`].
+const CHIP_PADDING: f32 = 6.0;
+
+/// The space after each token chip of a [`TextInput`].
+const CHIP_SPACING: f32 = 4.0;
+
+/// The space between the text of a [`TextInput`] and its leading and
+/// trailing elements.
+const SLOT_SPACING: f32 = 4.0;
+
+/// Returns the bounds of the "×" of a token chip with the given bounds.
+fn chip_remove_bounds(chip: Rectangle, remove_width: f32) -> Rectangle {
+    let width = remove_width + CHIP_PADDING;
+
+    Rectangle {
+        x: chip.x + chip.width - width,
+        width,
+        ..chip
+    }
+}
+
 /// The possible status of a [`TextInput`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -1755,6 +3137,18 @@ pub enum Status {
     Disabled,
 }
 
+/// When the value of a [`TextInput`] is validated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidateOn {
+    /// Every time the value changes.
+    #[default]
+    Input,
+    /// When the [`TextInput`] loses focus.
+    Blur,
+    /// Both when the value changes and when the [`TextInput`] loses focus.
+    Both,
+}
+
 /// The appearance of a text input.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
@@ -1782,6 +3176,14 @@ pub trait Catalog: Sized {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+
+    /// The [`Style`] of a class with the given status, while its value is
+    /// invalid.
+    ///
+    /// By default, this is the same as [`Catalog::style`].
+    fn invalid(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        self.style(class, status)
+    }
 }
 
 /// A styling function for a [`TextInput`].
@@ -1799,6 +3201,22 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
         class(self, status)
     }
+
+    fn invalid(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        let style = class(self, status);
+
+        if status == Status::Disabled {
+            return style;
+        }
+
+        Style {
+            border: Border {
+                color: self.extended_palette().danger.base.color,
+                ..style.border
+            },
+            ..style
+        }
+    }
 }
 
 /// The default style of a [`TextInput`].
@@ -1917,3 +3335,235 @@ pub fn focus_previous() -> Task<widget::Id> {
         iced_runtime::task::widget(operation::focusable::find_focused()),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::{Key, key};
+    use crate::core::widget::Id;
+    use crate::test::Harness;
+    use crate::widget::operation::WidgetState;
+
+    use iced_runtime::widget::operation;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Input(String),
+        Focused,
+        FocusedWith(String),
+        Blurred,
+    }
+
+    #[test]
+    fn scroll_offset_keeps_the_caret_at_the_start_visible() {
+        assert_eq!(scroll_offset(0.0, 300.0, 100.0, 0.0), 0.0);
+        assert_eq!(scroll_offset(0.0, 300.0, 100.0, 205.0), 0.0);
+    }
+
+    #[test]
+    fn scroll_offset_keeps_the_caret_in_the_middle_visible() {
+        let offset = scroll_offset(150.0, 300.0, 100.0, 0.0);
+
+        assert_eq!(offset, 55.0);
+
+        // Moving back within the visible region does not scroll
+        assert_eq!(scroll_offset(120.0, 300.0, 100.0, offset), offset);
+    }
+
+    #[test]
+    fn scroll_offset_keeps_the_caret_at_the_end_visible() {
+        assert_eq!(scroll_offset(300.0, 300.0, 100.0, 55.0), 205.0);
+    }
+
+    #[test]
+    fn scroll_offset_does_not_scroll_short_text() {
+        assert_eq!(scroll_offset(50.0, 50.0, 100.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn focus_changes_from_operations_publish_messages() {
+        let text_input: TextInput<'_, Message, crate::Theme, ()> =
+            TextInput::new("Name", "")
+                .id("name")
+                .width(100)
+                .on_input(Message::Input)
+                .on_focus(Message::Focused)
+                .on_blur(Message::Blurred);
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        let _ = harness.run::<()>(operation::focus("name"));
+        harness.move_cursor(Point::new(150.0, 50.0));
+
+        assert_eq!(harness.messages(), vec![Message::Focused]);
+
+        let _ = harness.run::<()>(crate::widget::operation::blur_all());
+        harness.move_cursor(Point::new(150.0, 60.0));
+
+        assert_eq!(harness.messages(), vec![Message::Blurred]);
+    }
+
+    #[test]
+    fn on_focus_with_receives_the_current_value() {
+        let text_input: TextInput<'_, Message, crate::Theme, ()> =
+            TextInput::new("Name", "Ada")
+                .id("name")
+                .width(100)
+                .on_input(Message::Input)
+                .on_focus_with(|value| Message::FocusedWith(value.to_owned()));
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        harness.click(Point::new(50.0, 10.0));
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::FocusedWith(String::from("Ada"))]
+        );
+
+        let _ = harness.run::<()>(crate::widget::operation::blur_all());
+        harness.move_cursor(Point::new(150.0, 50.0));
+        let _ = harness.run::<()>(operation::focus("name"));
+        harness.move_cursor(Point::new(150.0, 60.0));
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::FocusedWith(String::from("Ada"))]
+        );
+    }
+
+    fn max_length(value: &str) -> Harness<'static, Message> {
+        let text_input: TextInput<'_, Message, crate::Theme, ()> =
+            TextInput::new("Username", value)
+                .id("username")
+                .width(100)
+                .max_length(5)
+                .on_input(Message::Input);
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        let _ = harness.run::<()>(operation::focus("username"));
+        harness.tap_key(key::Named::End);
+
+        harness
+    }
+
+    #[test]
+    fn typing_stops_at_the_max_length() {
+        let mut harness = max_length("abc");
+
+        harness.type_text("defg");
+
+        assert_eq!(
+            harness.messages(),
+            vec![
+                Message::Input(String::from("abcd")),
+                Message::Input(String::from("abcde")),
+            ]
+        );
+    }
+
+    #[test]
+    fn pasting_truncates_to_the_max_length() {
+        let mut harness = max_length("ab");
+
+        harness.set_clipboard("cdéfgh");
+        let _ = harness.set_modifiers(keyboard::Modifiers::COMMAND);
+        harness.tap_key(Key::Character("v".into()));
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::Input(String::from("abcdé"))]
+        );
+    }
+
+    fn selection(harness: &mut Harness<'_, Message>) -> Option<(usize, usize)> {
+        let snapshot = harness
+            .run(crate::widget::operation::ui_snapshot())
+            .pop()
+            .unwrap();
+
+        match snapshot.get(&Id::new("name")).unwrap().state {
+            Some(WidgetState::TextInput { selection, .. }) => selection,
+            ref state => panic!("unexpected state: {state:?}"),
+        }
+    }
+
+    #[test]
+    fn select_on_focus_selects_the_whole_value() {
+        let text_input = || -> TextInput<'_, Message, crate::Theme, ()> {
+            TextInput::new("Name", "Hello")
+                .id("name")
+                .width(100)
+                .on_input(Message::Input)
+                .select_on_focus(true)
+        };
+
+        let mut clicked = Harness::new(text_input(), Size::new(200.0, 100.0));
+        clicked.click(Point::new(50.0, 10.0));
+
+        assert_eq!(selection(&mut clicked), Some((0, 5)));
+
+        let mut focused = Harness::new(text_input(), Size::new(200.0, 100.0));
+        let _ = focused.run::<()>(operation::focus("name"));
+        focused.move_cursor(Point::new(150.0, 50.0));
+
+        assert_eq!(selection(&mut focused), Some((0, 5)));
+    }
+
+    #[test]
+    fn debounced_input_is_published_once_after_rapid_edits() {
+        let delay = Duration::from_secs(1);
+
+        let text_input: TextInput<'_, Message, crate::Theme, ()> =
+            TextInput::new("Search", "")
+                .id("search")
+                .width(100)
+                .on_input_debounced(delay, Message::Input);
+
+        let mut harness = Harness::new(text_input, Size::new(200.0, 100.0));
+
+        let _ = harness.run::<()>(operation::focus("search"));
+        harness.type_text("abc");
+        harness.redraw(Instant::now());
+
+        assert_eq!(harness.messages(), vec![]);
+
+        harness.redraw(Instant::now() + delay);
+        harness.redraw(Instant::now() + delay * 2);
+
+        assert_eq!(
+            harness.messages(),
+            vec![Message::Input(String::from("abc"))]
+        );
+    }
+
+    #[test]
+    fn themes_without_a_menu_catalog_can_be_used() {
+        struct Plain;
+
+        impl Catalog for Plain {
+            type Class<'a> = ();
+
+            fn default<'a>() -> Self::Class<'a> {}
+
+            fn style(&self, _class: &(), status: Status) -> Style {
+                default(&Theme::Light, status)
+            }
+        }
+
+        let text_input: TextInput<'_, Message, Plain, ()> =
+            TextInput::new("Name", "")
+                .id("name")
+                .width(100)
+                .on_input(Message::Input);
+
+        let mut harness: Harness<'_, Message, Plain> =
+            Harness::new(text_input, Size::new(200.0, 100.0));
+
+        let _ = harness.run::<()>(operation::focus("name"));
+        harness.type_text("a");
+
+        assert_eq!(harness.messages(), vec![Message::Input(String::from("a"))]);
+    }
+}
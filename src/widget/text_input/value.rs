@@ -4,7 +4,7 @@ use unicode_segmentation::UnicodeSegmentation;
 ///
 /// [`TextInput`]: super::TextInput
 // TODO: Reduce allocations, cache results (?)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Value {
     graphemes: Vec<String>,
 }
@@ -37,7 +37,8 @@ impl Value {
         let previous_string =
             &self.graphemes[..index.min(self.graphemes.len())].concat();
 
-        UnicodeSegmentation::split_word_bound_indices(previous_string as &str)
+        word_bound_indices(previous_string)
+            .into_iter()
             .filter(|(_, word)| !word.trim_start().is_empty())
             .next_back()
             .map(|(i, previous_word)| {
@@ -58,7 +59,8 @@ impl Value {
     pub fn next_end_of_word(&self, index: usize) -> usize {
         let next_string = &self.graphemes[index..].concat();
 
-        UnicodeSegmentation::split_word_bound_indices(next_string as &str)
+        word_bound_indices(next_string)
+            .into_iter()
             .find(|(_, word)| !word.trim_start().is_empty())
             .map(|(i, next_word)| {
                 index
@@ -72,6 +74,31 @@ impl Value {
             .unwrap_or(self.len())
     }
 
+    /// Returns the start and end positions of the word containing the
+    /// grapheme at the given `index`.
+    ///
+    /// Runs of whitespace and runs of punctuation count as words of their
+    /// own, using the same boundaries as word navigation. An `index` past the
+    /// end refers to the last grapheme.
+    pub fn word_at(&self, index: usize) -> (usize, usize) {
+        let string = self.to_string();
+        let index = index.min(self.len().saturating_sub(1));
+        let mut start = 0;
+
+        for (_, word) in word_bound_indices(&string) {
+            let end =
+                start + UnicodeSegmentation::graphemes(word, true).count();
+
+            if index < end {
+                return (start, end);
+            }
+
+            start = end;
+        }
+
+        (self.len(), self.len())
+    }
+
     /// Returns a new [`Value`] containing the graphemes from `start` until the
     /// given `end`.
     pub fn select(&self, start: usize, end: usize) -> Self {
@@ -134,3 +161,97 @@ impl std::fmt::Display for Value {
         f.write_str(&self.graphemes.concat())
     }
 }
+
+/// Splits the given string on word boundaries, like
+/// [`UnicodeSegmentation::split_word_bound_indices`], but treating runs of
+/// punctuation (e.g. `...` or `->`) as a single word.
+///
+/// Scripts without spaces between words (e.g. CJK) are split into single
+/// characters, as there is no dictionary-based segmentation.
+fn word_bound_indices(string: &str) -> Vec<(usize, &str)> {
+    let mut words: Vec<(usize, &str)> = Vec::new();
+
+    for (i, word) in UnicodeSegmentation::split_word_bound_indices(string) {
+        if let Some((start, last)) = words.last_mut()
+            && is_punctuation(last)
+            && is_punctuation(word)
+        {
+            *last = &string[*start..i + word.len()];
+        } else {
+            words.push((i, word));
+        }
+    }
+
+    words
+}
+
+fn is_punctuation(word: &str) -> bool {
+    word.chars()
+        .all(|c| !c.is_alphanumeric() && !c.is_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dotted_words_are_a_single_word() {
+        let value = Value::new("foo.bar");
+
+        assert_eq!(value.next_end_of_word(0), 7);
+        assert_eq!(value.previous_start_of_word(7), 0);
+    }
+
+    #[test]
+    fn runs_of_spaces_are_skipped() {
+        let value = Value::new("a   b");
+
+        assert_eq!(value.next_end_of_word(1), 5);
+        assert_eq!(value.previous_start_of_word(4), 0);
+        assert_eq!(value.previous_start_of_word(5), 4);
+    }
+
+    #[test]
+    fn runs_of_punctuation_are_a_single_word() {
+        let value = Value::new("foo...bar");
+
+        assert_eq!(value.next_end_of_word(0), 3);
+        assert_eq!(value.next_end_of_word(3), 6);
+        assert_eq!(value.next_end_of_word(6), 9);
+        assert_eq!(value.previous_start_of_word(9), 6);
+        assert_eq!(value.previous_start_of_word(6), 3);
+        assert_eq!(value.previous_start_of_word(3), 0);
+    }
+
+    #[test]
+    fn cjk_text_moves_by_character() {
+        let value = Value::new("日本語 text");
+
+        assert_eq!(value.next_end_of_word(0), 1);
+        assert_eq!(value.next_end_of_word(2), 3);
+        assert_eq!(value.next_end_of_word(3), 8);
+        assert_eq!(value.previous_start_of_word(8), 4);
+        assert_eq!(value.previous_start_of_word(4), 2);
+    }
+
+    #[test]
+    fn word_at_selects_words_between_punctuation() {
+        let value = Value::new("foo, bar...baz");
+
+        assert_eq!(value.word_at(1), (0, 3));
+        assert_eq!(value.word_at(3), (3, 4));
+        assert_eq!(value.word_at(5), (5, 8));
+        assert_eq!(value.word_at(9), (8, 11));
+        assert_eq!(value.word_at(13), (11, 14));
+    }
+
+    #[test]
+    fn word_at_selects_runs_of_whitespace() {
+        let value = Value::new("a   b");
+
+        assert_eq!(value.word_at(0), (0, 1));
+        assert_eq!(value.word_at(2), (1, 4));
+        assert_eq!(value.word_at(4), (4, 5));
+        assert_eq!(value.word_at(10), (4, 5));
+    }
+}